@@ -8,6 +8,8 @@ static GLOBAL: Jemalloc = Jemalloc;
 use std::{fs::File, io::Read, time::Duration};
 
 use binance_schemes::spot::{general::ExchangeInfo, market::OrderBook};
+#[cfg(feature = "simd_json")]
+use binancex::{JsonDecoder, SimdJsonDecoder};
 use criterion::{criterion_group, BatchSize, BenchmarkId, Criterion, Throughput};
 
 macro_rules! bench_file {
@@ -37,6 +39,35 @@ macro_rules! bench_file {
                 },
             );
 
+            group.bench_with_input(
+                BenchmarkId::new("simd-json::from_slice_with_buffers", stringify!($type_name)),
+                &buf,
+                |b, input| {
+                    let mut buffers = simd_json::Buffers::new(input.len());
+                    b.iter_batched(
+                        || input.clone(),
+                        |mut bytes| {
+                            let _json: $type_name =
+                                simd_json::serde::from_slice_with_buffers(&mut bytes, &mut buffers).unwrap();
+                        },
+                        BatchSize::$batch_size,
+                    )
+                },
+            );
+
+            // copies the body into a pooled buffer, like the client does
+            #[cfg(feature = "simd_json")]
+            group.bench_with_input(
+                BenchmarkId::new("binancex::SimdJsonDecoder", stringify!($type_name)),
+                &buf,
+                |b, input| {
+                    let decoder = SimdJsonDecoder::default();
+                    b.iter(|| {
+                        let _json: $type_name = decoder.decode(input).unwrap();
+                    })
+                },
+            );
+
             group.bench_with_input(
                 BenchmarkId::new("serde_json::from_slice", stringify!($type_name)),
                 &buf,
@@ -6,3 +6,19 @@ pub struct PriceLevel {
     pub price: Decimal,
     pub qty: Decimal,
 }
+
+/// [`PriceLevel`] borrowing the price and quantity text from the response body.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PriceLevelRef<'a> {
+    pub price: &'a str,
+    pub qty: &'a str,
+}
+
+impl PriceLevelRef<'_> {
+    pub fn parse(&self) -> Result<PriceLevel, rust_decimal::Error> {
+        Ok(PriceLevel {
+            price: self.price.parse()?,
+            qty: self.qty.parse()?,
+        })
+    }
+}
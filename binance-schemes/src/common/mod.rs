@@ -1,6 +1,8 @@
 pub mod general;
 pub mod market;
 
+use binance_types::time::Timestamp;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -10,5 +12,5 @@ pub struct Pong {}
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ServerTime {
-    pub server_time: u64,
+    pub server_time: Timestamp,
 }
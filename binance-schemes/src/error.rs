@@ -1,6 +1,25 @@
 use crate::spot::trade::CancelOrderRes;
+use binance_types::error::{ErrorCategory, ErrorCode, Market};
+use binance_types::time::Timestamp;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Error payload of the Binance API.
+pub trait ApiError {
+    fn code(&self) -> i64;
+
+    fn msg(&self) -> &str;
+
+    #[inline]
+    fn error_code(&self) -> ErrorCode {
+        ErrorCode::from_code(self.code())
+    }
+
+    #[inline]
+    fn category(&self) -> ErrorCategory {
+        self.error_code().category_with_msg(self.msg())
+    }
+}
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Error {
@@ -8,6 +27,54 @@ pub struct Error {
     pub msg: String,
 }
 
+impl ApiError for Error {
+    #[inline]
+    fn code(&self) -> i64 {
+        self.code
+    }
+
+    #[inline]
+    fn msg(&self) -> &str {
+        &self.msg
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code, self.msg)
+    }
+}
+
+/// Error payload of the futures APIs, whose codes are read with their futures meaning.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FuturesError {
+    pub code: i64,
+    pub msg: String,
+}
+
+impl ApiError for FuturesError {
+    #[inline]
+    fn code(&self) -> i64 {
+        self.code
+    }
+
+    #[inline]
+    fn msg(&self) -> &str {
+        &self.msg
+    }
+
+    #[inline]
+    fn error_code(&self) -> ErrorCode {
+        ErrorCode::from_code_in(self.code, Market::Futures)
+    }
+}
+
+impl std::fmt::Display for FuturesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code, self.msg)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CancelReplaceOrderError {
     pub code: i64,
@@ -15,6 +82,24 @@ pub struct CancelReplaceOrderError {
     pub data: Data,
 }
 
+impl ApiError for CancelReplaceOrderError {
+    #[inline]
+    fn code(&self) -> i64 {
+        self.code
+    }
+
+    #[inline]
+    fn msg(&self) -> &str {
+        &self.msg
+    }
+}
+
+impl std::fmt::Display for CancelReplaceOrderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code, self.msg)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Data {
@@ -31,7 +116,7 @@ pub enum CancelResponse {
     Failure(Error),
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
 pub enum NewOrderResponse {
     #[serde(rename_all = "camelCase")]
@@ -40,8 +125,56 @@ pub enum NewOrderResponse {
         order_id: u64,
         order_list_id: i64,
         client_order_id: String,
-        transact_time: u64,
+        transact_time: Timestamp,
     },
     Failure(Error),
     None,
 }
+
+// Not an untagged enum: serde buffers those, which loses the unit of `transact_time`.
+impl<'de> Deserialize<'de> for NewOrderResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Raw {
+            symbol: Option<String>,
+            order_id: Option<u64>,
+            order_list_id: Option<i64>,
+            client_order_id: Option<String>,
+            transact_time: Option<Timestamp>,
+            code: Option<i64>,
+            msg: Option<String>,
+        }
+
+        let Some(raw) = Option::<Raw>::deserialize(deserializer)? else {
+            return Ok(NewOrderResponse::None);
+        };
+        match raw {
+            Raw {
+                symbol: Some(symbol),
+                order_id: Some(order_id),
+                order_list_id: Some(order_list_id),
+                client_order_id: Some(client_order_id),
+                transact_time: Some(transact_time),
+                ..
+            } => Ok(NewOrderResponse::Success {
+                symbol,
+                order_id,
+                order_list_id,
+                client_order_id,
+                transact_time,
+            }),
+            Raw {
+                code: Some(code),
+                msg: Some(msg),
+                ..
+            } => Ok(NewOrderResponse::Failure(Error { code, msg })),
+            _ => Err(serde::de::Error::custom(
+                "data did not match any variant of enum NewOrderResponse",
+            )),
+        }
+    }
+}
@@ -2,6 +2,7 @@ pub use crate::common::general::*;
 use crate::futures_common::trade::TimeInForce;
 
 use binance_types::futures_dapi::general::*;
+use binance_types::time::Timestamp;
 
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -57,8 +58,8 @@ pub struct Symbol {
     pub symbol: String,
     pub pair: String,
     pub contract_type: ContractType,
-    pub delivery_date: u64,
-    pub onboard_date: u64,
+    pub delivery_date: Timestamp,
+    pub onboard_date: Timestamp,
     pub contract_status: ContractStatus,
 
     // TODO: max_value?
@@ -90,7 +91,7 @@ pub struct Symbol {
 #[serde(rename_all = "camelCase")]
 pub struct ExchangeInfo {
     pub timezone: String,
-    pub server_time: u64,
+    pub server_time: Timestamp,
     pub rate_limits: Vec<RateLimit>,
     pub exchange_filters: Vec<ExchangeFilter>,
     pub symbols: Vec<Symbol>,
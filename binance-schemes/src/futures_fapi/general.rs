@@ -2,6 +2,7 @@ pub use crate::common::general::*;
 use crate::futures_common::trade::TimeInForce;
 
 use binance_types::futures_fapi::general::*;
+use binance_types::time::Timestamp;
 
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -58,8 +59,8 @@ pub struct Symbol {
     pub symbol: String,
     pub pair: String,
     pub contract_type: ContractType,
-    pub delivery_date: u64,
-    pub onboard_date: u64,
+    pub delivery_date: Timestamp,
+    pub onboard_date: Timestamp,
     pub status: SymbolStatus,
     pub maint_margin_percent: Decimal,
     pub required_margin_percent: Decimal,
@@ -92,7 +93,7 @@ pub struct Asset {
 #[serde(rename_all = "camelCase")]
 pub struct ExchangeInfo {
     pub timezone: String,
-    pub server_time: u64,
+    pub server_time: Timestamp,
     pub futures_type: String,
     pub rate_limits: Vec<RateLimit>,
     pub exchange_filters: Vec<ExchangeFilter>,
@@ -1,4 +1,5 @@
 pub use crate::common::market::PriceLevel;
+use binance_types::time::Timestamp;
 
 use serde::{Deserialize, Serialize};
 
@@ -8,10 +9,10 @@ pub struct OrderBook {
     pub last_update_id: u64,
 
     #[serde(rename = "E")]
-    pub event_time: u64,
+    pub event_time: Timestamp,
 
     #[serde(rename = "T")]
-    pub transaction_time: u64,
+    pub transaction_time: Timestamp,
 
     pub asks: Vec<PriceLevel>,
     pub bids: Vec<PriceLevel>,
@@ -1,6 +1,7 @@
 pub use crate::common::general::*;
 
 use binance_types::spot::{general::*, permissions::Permission};
+use binance_types::time::Timestamp;
 
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -116,7 +117,7 @@ pub struct Symbol {
 #[serde(rename_all = "camelCase")]
 pub struct ExchangeInfo {
     pub timezone: String,
-    pub server_time: u64,
+    pub server_time: Timestamp,
     pub rate_limits: Vec<RateLimit>,
     pub exchange_filters: Vec<ExchangeFilter>,
     pub symbols: Vec<Symbol>,
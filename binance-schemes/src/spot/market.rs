@@ -1,4 +1,5 @@
-pub use crate::common::market::PriceLevel;
+pub use crate::common::market::{PriceLevel, PriceLevelRef};
+use binance_types::time::Timestamp;
 
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -11,6 +12,17 @@ pub struct OrderBook {
     pub bids: Vec<PriceLevel>,
 }
 
+/// [`OrderBook`] borrowing the price levels from the response body.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBookRef<'a> {
+    pub last_update_id: u64,
+    #[serde(borrow)]
+    pub asks: Vec<PriceLevelRef<'a>>,
+    #[serde(borrow)]
+    pub bids: Vec<PriceLevelRef<'a>>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Trade {
@@ -18,7 +30,20 @@ pub struct Trade {
     pub price: Decimal,
     pub qty: Decimal,
     pub quote_qty: Decimal,
-    pub time: u64, // Trade executed timestamp, as same as `T` in the stream
+    pub time: Timestamp, // Trade executed timestamp, as same as `T` in the stream
+    pub is_buyer_maker: bool,
+    pub is_best_match: bool,
+}
+
+/// [`Trade`] borrowing the decimals from the response body.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeRef<'a> {
+    pub id: u64,
+    pub price: &'a str,
+    pub qty: &'a str,
+    pub quote_qty: &'a str,
+    pub time: Timestamp,
     pub is_buyer_maker: bool,
     pub is_best_match: bool,
 }
@@ -35,7 +60,7 @@ pub struct AggregateTrade {
     pub last_trade_id: u64,
 
     #[serde(rename = "T")]
-    pub timestamp: u64,
+    pub timestamp: Timestamp,
 
     #[serde(rename = "m")]
     pub is_maker: bool,
@@ -53,13 +78,13 @@ pub struct AggregateTrade {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct KlineSummary {
-    pub open_time: u64,
+    pub open_time: Timestamp,
     pub open_price: Decimal,
     pub high_price: Decimal,
     pub low_price: Decimal,
     pub close_price: Decimal,
     pub volume: Decimal,
-    pub close_time: u64,
+    pub close_time: Timestamp,
     pub quote_asset_volume: Decimal,
     pub number_of_trades: u64,
     pub taker_buy_base_asset_volume: Decimal,
@@ -92,8 +117,8 @@ pub struct TickerStatsFull {
     pub low_price: Decimal,
     pub volume: Decimal,
     pub quote_volume: Decimal,
-    pub open_time: u64,
-    pub close_time: u64,
+    pub open_time: Timestamp,
+    pub close_time: Timestamp,
     pub first_id: i64, // First tradeId
     pub last_id: i64,  // Last tradeId
     pub count: u64,    // Trade count
@@ -110,8 +135,8 @@ pub struct TickerStatsMini {
     pub last_price: Decimal,   // Closing price of the interval
     pub volume: Decimal,       // Total trade volume (in base asset)
     pub quote_volume: Decimal, // Total trade volume (in quote asset)
-    pub open_time: u64,        // Start of the ticker interval
-    pub close_time: u64,       // End of the ticker interval
+    pub open_time: Timestamp,  // Start of the ticker interval
+    pub close_time: Timestamp, // End of the ticker interval
     pub first_id: i64,         // First tradeId considered
     pub last_id: i64,          // Last tradeId considered
     pub count: u64,            // Total trade count
@@ -132,3 +157,14 @@ pub struct BookTicker {
     pub ask_price: Decimal,
     pub ask_qty: Decimal,
 }
+
+/// [`BookTicker`] borrowing the symbol and the decimals from the response body.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTickerRef<'a> {
+    pub symbol: &'a str,
+    pub bid_price: &'a str,
+    pub bid_qty: &'a str,
+    pub ask_price: &'a str,
+    pub ask_qty: &'a str,
+}
@@ -1,8 +1,9 @@
 use crate::common::general::{RateLimitInterval, RateLimitType};
 use binance_types::spot::general::{OrderStatus, OrderType};
+use binance_types::time::Timestamp;
 
 use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -243,7 +244,7 @@ pub struct CancelReplaceOrderRes {
     new_order_response: NewOrderRes,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
 pub enum NewOrderRes {
     Full(TransactionFull),
@@ -251,6 +252,104 @@ pub enum NewOrderRes {
     Ack(TransactionAck),
 }
 
+// Not an untagged enum: serde buffers those, which loses the unit of `transact_time`.
+impl<'de> Deserialize<'de> for NewOrderRes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Raw {
+            symbol: String,
+            order_id: u64,
+            order_list_id: i64,
+            client_order_id: String,
+            transact_time: Timestamp,
+            price: Option<Decimal>,
+            orig_qty: Option<Decimal>,
+            executed_qty: Option<Decimal>,
+            cummulative_quote_qty: Option<Decimal>,
+            status: Option<OrderStatus>,
+            time_in_force: Option<TimeInForce>,
+            #[serde(rename = "type")]
+            order_type: Option<OrderType>,
+            side: Option<OrderSide>,
+            strategy_id: Option<u32>,
+            strategy_type: Option<u32>,
+            fills: Option<Vec<TransactionFill>>,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        let (
+            Some(price),
+            Some(orig_qty),
+            Some(executed_qty),
+            Some(cummulative_quote_qty),
+            Some(status),
+            Some(time_in_force),
+            Some(order_type),
+            Some(side),
+        ) = (
+            raw.price,
+            raw.orig_qty,
+            raw.executed_qty,
+            raw.cummulative_quote_qty,
+            raw.status,
+            raw.time_in_force,
+            raw.order_type,
+            raw.side,
+        )
+        else {
+            return Ok(NewOrderRes::Ack(TransactionAck {
+                symbol: raw.symbol,
+                order_id: raw.order_id,
+                order_list_id: raw.order_list_id,
+                client_order_id: raw.client_order_id,
+                transact_time: raw.transact_time,
+            }));
+        };
+
+        Ok(match raw.fills {
+            Some(fills) => NewOrderRes::Full(TransactionFull {
+                symbol: raw.symbol,
+                order_id: raw.order_id,
+                order_list_id: raw.order_list_id,
+                client_order_id: raw.client_order_id,
+                transact_time: raw.transact_time,
+                price,
+                orig_qty,
+                executed_qty,
+                cummulative_quote_qty,
+                status,
+                time_in_force,
+                order_type,
+                side,
+                strategy_id: raw.strategy_id,
+                strategy_type: raw.strategy_type,
+                fills,
+            }),
+            None => NewOrderRes::Result(TransactionResult {
+                symbol: raw.symbol,
+                order_id: raw.order_id as i64,
+                order_list_id: raw.order_list_id,
+                client_order_id: raw.client_order_id,
+                transact_time: raw.transact_time,
+                price,
+                orig_qty,
+                executed_qty,
+                cummulative_quote_qty,
+                status,
+                time_in_force,
+                order_type,
+                side,
+                strategy_id: raw.strategy_id,
+                strategy_type: raw.strategy_type,
+            }),
+        })
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountInfo {
@@ -262,7 +361,7 @@ pub struct AccountInfo {
     pub can_withdraw: bool,
     pub can_deposit: bool,
     pub brokered: bool,
-    pub update_time: u64,
+    pub update_time: Timestamp,
     pub account_type: String,
     pub balances: Vec<Asset>,
     pub permissions: Vec<String>,
@@ -286,7 +385,7 @@ pub struct TransactionAck {
     pub order_id: u64,
     pub order_list_id: i64, // Unless OCO, value will be -1
     pub client_order_id: String,
-    pub transact_time: u64,
+    pub transact_time: Timestamp,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -296,7 +395,7 @@ pub struct TransactionResult {
     pub order_id: i64,
     pub order_list_id: i64, // Unless OCO, value will be -1
     pub client_order_id: String,
-    pub transact_time: u64,
+    pub transact_time: Timestamp,
     pub price: Decimal,
     pub orig_qty: Decimal,
     pub executed_qty: Decimal,
@@ -321,7 +420,7 @@ pub struct TransactionFull {
     pub order_id: u64,
     pub order_list_id: i64, // Unless OCO, value will be -1
     pub client_order_id: String,
-    pub transact_time: u64,
+    pub transact_time: Timestamp,
     pub price: Decimal,
     pub orig_qty: Decimal,
     pub executed_qty: Decimal,
@@ -402,8 +501,8 @@ pub struct OrderInfo {
     pub side: OrderSide,
     pub stop_price: Decimal,
     pub iceberg_qty: Decimal,
-    pub time: u64,
-    pub update_time: u64,
+    pub time: Timestamp,
+    pub update_time: Timestamp,
     pub is_working: bool,
     pub orig_quote_order_qty: Decimal,
 }
@@ -473,7 +572,7 @@ pub struct OcoOrderRes {
     pub list_status_type: OcoOrderStatusType,
     pub list_order_status: OcoOrderStatus,
     pub list_client_order_id: String,
-    pub transaction_time: u64,
+    pub transaction_time: Timestamp,
     pub symbol: String,
     pub orders: Vec<OcoOrder>,
     pub order_reports: Vec<OrderReport>,
@@ -494,7 +593,7 @@ pub struct OrderReport {
     pub order_id: u64,
     pub order_list_id: u64,
     pub client_order_id: String,
-    pub transact_time: u64,
+    pub transact_time: Timestamp,
     pub price: Decimal,
     pub orig_qty: Decimal,
     pub executed_qty: Decimal,
@@ -539,7 +638,7 @@ pub struct AccountTrade {
     pub quote_qty: Decimal,
     pub commission: Decimal,
     pub commission_asset: String,
-    pub time: u64,
+    pub time: Timestamp,
     pub is_buyer: bool,
     pub is_maker: bool,
     pub is_best_match: bool,
@@ -1,4 +1,5 @@
 use crate::common::market::PriceLevel;
+use binance_types::time::Timestamp;
 
 use serde::{Deserialize, Serialize};
 
@@ -9,7 +10,7 @@ pub struct Depth {
     pub event_type: String,
 
     #[serde(rename = "E")]
-    pub event_time: u64,
+    pub event_time: Timestamp,
 
     #[serde(rename = "s")]
     pub symbol: String,
@@ -0,0 +1,491 @@
+use serde::{Deserialize, Serialize};
+
+/// Broad classes of Binance errors, for handling without matching single codes.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ErrorCategory {
+    /// Internal errors, disconnects and timeouts on the Binance side.
+    Server,
+    /// Request weight, order count or IP limits are exceeded.
+    RateLimit,
+    /// Invalid API key, signature or permissions.
+    Auth,
+    /// The timestamp is outside of `recvWindow`, the local clock needs a resync.
+    Timestamp,
+    /// Malformed, missing or unknown parameters.
+    InvalidRequest,
+    /// The order violates a symbol filter (price, quantity, notional...).
+    FilterFailure,
+    InsufficientBalance,
+    /// The order to query or cancel does not exist.
+    UnknownOrder,
+    /// The order was rejected for another reason.
+    OrderRejected,
+    Unknown,
+}
+
+/// Market of the API an error code was returned by.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Market {
+    #[default]
+    Spot,
+    /// USDⓈ-M and COIN-M futures.
+    Futures,
+}
+
+macro_rules! error_codes {
+    (
+        shared {
+            $(
+                $(#[$attr:meta])*
+                $variant:ident = $code:literal, $category:ident, $retryable:literal;
+            )*
+        }
+        futures {
+            $(
+                $(#[$futures_attr:meta])*
+                $futures_variant:ident = $futures_code:literal, $futures_category:ident, $futures_retryable:literal;
+            )*
+        }
+    ) => {
+        /// Binance API error code.
+        ///
+        /// A few codes mean something else on futures than on spot, these have a variant
+        /// of their own that only [`ErrorCode::from_code_in`] returns for [`Market::Futures`].
+        /// Codes missing here are kept as `Unknown`.
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+        #[serde(from = "i64", into = "i64")]
+        pub enum ErrorCode {
+            $(
+                $(#[$attr])*
+                $variant,
+            )*
+            $(
+                $(#[$futures_attr])*
+                $futures_variant,
+            )*
+            Unknown(i64),
+        }
+
+        impl ErrorCode {
+            /// The code with its spot meaning, see `from_code_in`.
+            pub fn from_code(code: i64) -> Self {
+                match code {
+                    $($code => ErrorCode::$variant,)*
+                    code => ErrorCode::Unknown(code),
+                }
+            }
+
+            /// The code with its meaning on `market`.
+            pub fn from_code_in(code: i64, market: Market) -> Self {
+                if market == Market::Futures {
+                    match code {
+                        $($futures_code => return ErrorCode::$futures_variant,)*
+                        _ => {}
+                    }
+                }
+                ErrorCode::from_code(code)
+            }
+
+            pub fn code(&self) -> i64 {
+                match self {
+                    $(ErrorCode::$variant => $code,)*
+                    $(ErrorCode::$futures_variant => $futures_code,)*
+                    ErrorCode::Unknown(code) => *code,
+                }
+            }
+
+            pub fn category(&self) -> ErrorCategory {
+                match self {
+                    $(ErrorCode::$variant => ErrorCategory::$category,)*
+                    $(ErrorCode::$futures_variant => ErrorCategory::$futures_category,)*
+                    ErrorCode::Unknown(_) => ErrorCategory::Unknown,
+                }
+            }
+
+            /// Whether the same request may succeed when sent again later,
+            /// possibly after a backoff or a clock resync.
+            pub fn is_retryable(&self) -> bool {
+                match self {
+                    $(ErrorCode::$variant => $retryable,)*
+                    $(ErrorCode::$futures_variant => $futures_retryable,)*
+                    ErrorCode::Unknown(_) => false,
+                }
+            }
+        }
+    };
+}
+
+impl ErrorCode {
+    /// The request may have been executed, e.g. the order placed, despite the error.
+    /// Check before sending it again.
+    pub fn is_execution_status_unknown(&self) -> bool {
+        matches!(self, ErrorCode::UnexpectedResponse | ErrorCode::Timeout)
+    }
+}
+
+#[rustfmt::skip]
+error_codes! {
+shared {
+    // 10xx - general server or network issues
+    UnknownError = -1000, Server, true;
+    Disconnected = -1001, Server, true;
+    Unauthorized = -1002, Auth, false;
+    TooManyRequests = -1003, RateLimit, true;
+    DuplicateIp = -1004, RateLimit, false;
+    NoSuchIp = -1005, Auth, false;
+    /// The execution status of the request is unknown.
+    UnexpectedResponse = -1006, Server, false;
+    /// The execution status of the request is unknown.
+    Timeout = -1007, Server, false;
+    ServerBusy = -1008, Server, true;
+    ErrorMsgReceived = -1010, Server, false;
+    /// The IP address is not on the whitelist of the API key.
+    NonWhiteList = -1011, Auth, false;
+    InvalidMessage = -1013, FilterFailure, false;
+    UnknownOrderComposition = -1014, InvalidRequest, false;
+    TooManyOrders = -1015, RateLimit, true;
+    ServiceShuttingDown = -1016, Server, true;
+    UnsupportedOperation = -1020, InvalidRequest, false;
+    InvalidTimestamp = -1021, Timestamp, true;
+    InvalidSignature = -1022, Auth, false;
+    StartTimeGreaterThanEndTime = -1023, InvalidRequest, false;
+    NotFound = -1099, Auth, false;
+
+    // 11xx - request issues
+    IllegalChars = -1100, InvalidRequest, false;
+    TooManyParameters = -1101, InvalidRequest, false;
+    MandatoryParamEmptyOrMalformed = -1102, InvalidRequest, false;
+    UnknownParam = -1103, InvalidRequest, false;
+    UnreadParameters = -1104, InvalidRequest, false;
+    ParamEmpty = -1105, InvalidRequest, false;
+    ParamNotRequired = -1106, InvalidRequest, false;
+    ParamOverflow = -1108, InvalidRequest, false;
+    BadAccount = -1109, InvalidRequest, false;
+    BadInstrumentType = -1110, InvalidRequest, false;
+    BadPrecision = -1111, InvalidRequest, false;
+    NoDepth = -1112, OrderRejected, false;
+    WithdrawNotNegative = -1113, InvalidRequest, false;
+    TifNotRequired = -1114, InvalidRequest, false;
+    InvalidTif = -1115, InvalidRequest, false;
+    InvalidOrderType = -1116, InvalidRequest, false;
+    InvalidSide = -1117, InvalidRequest, false;
+    EmptyNewClOrdId = -1118, InvalidRequest, false;
+    EmptyOrgClOrdId = -1119, InvalidRequest, false;
+    BadInterval = -1120, InvalidRequest, false;
+    BadSymbol = -1121, InvalidRequest, false;
+    InvalidSymbolStatus = -1122, InvalidRequest, false;
+    InvalidListenKey = -1125, InvalidRequest, false;
+    AssetNotSupported = -1126, InvalidRequest, false;
+    MoreThanXxHours = -1127, InvalidRequest, false;
+    OptionalParamsBadCombo = -1128, InvalidRequest, false;
+    InvalidParameter = -1130, InvalidRequest, false;
+    BadRecvWindow = -1131, InvalidRequest, false;
+    BadStrategyType = -1134, InvalidRequest, false;
+    InvalidJson = -1135, InvalidRequest, false;
+    InvalidNewOrderRespType = -1136, InvalidRequest, false;
+    InvalidTickerType = -1139, InvalidRequest, false;
+    InvalidCancelRestrictions = -1145, InvalidRequest, false;
+    DuplicateSymbols = -1151, InvalidRequest, false;
+    InvalidSbeHeader = -1152, InvalidRequest, false;
+    UnsupportedSchemaId = -1153, InvalidRequest, false;
+    SbeDisabled = -1155, InvalidRequest, false;
+    OcoOrderTypeRejected = -1158, InvalidRequest, false;
+    OcoIcebergQtyTimeInForce = -1160, InvalidRequest, false;
+    DeprecatedSchema = -1161, InvalidRequest, false;
+    BuyOcoLimitMustBeBelow = -1165, FilterFailure, false;
+    SellOcoLimitMustBeAbove = -1166, FilterFailure, false;
+    BothOcoOrdersCannotBeLimit = -1168, InvalidRequest, false;
+    InvalidTimeUnit = -1194, InvalidRequest, false;
+    BuyOcoStopLossMustBeAbove = -1196, FilterFailure, false;
+    SellOcoStopLossMustBeBelow = -1197, FilterFailure, false;
+    BuyOcoTakeProfitMustBeBelow = -1198, FilterFailure, false;
+    SellOcoTakeProfitMustBeAbove = -1199, FilterFailure, false;
+
+    // 20xx - processing issues
+    /// Also used for insufficient balance and other rejections, see the message.
+    NewOrderRejected = -2010, OrderRejected, false;
+    /// Also used for unknown orders, see the message.
+    CancelRejected = -2011, OrderRejected, false;
+    CancelAllFail = -2012, OrderRejected, false;
+    NoSuchOrder = -2013, UnknownOrder, false;
+    BadApiKeyFormat = -2014, Auth, false;
+    RejectedMbxKey = -2015, Auth, false;
+    NoTradingWindow = -2016, OrderRejected, false;
+    ApiKeysLocked = -2017, Auth, false;
+    BalanceNotSufficient = -2018, InsufficientBalance, false;
+    MarginNotSufficient = -2019, InsufficientBalance, false;
+    UnableToFill = -2020, OrderRejected, false;
+    OrderCancelReplacePartiallyFailed = -2021, OrderRejected, false;
+    OrderCancelReplaceFailed = -2022, OrderRejected, false;
+    UserInLiquidation = -2023, OrderRejected, false;
+    PositionNotSufficient = -2024, InsufficientBalance, false;
+    MaxOpenOrderExceeded = -2025, RateLimit, false;
+    OrderArchived = -2026, UnknownOrder, false;
+    MaxLeverageRatio = -2027, OrderRejected, false;
+    MinLeverageRatio = -2028, InsufficientBalance, false;
+    ClientOrderIdInvalid = -2039, InvalidRequest, false;
+
+    // 30xx - margin issues
+    InnerFailure = -3000, Server, true;
+    NeedEnable2fa = -3001, Auth, false;
+    AssetDeficiency = -3002, InvalidRequest, false;
+    NoOpenedMarginAccount = -3003, InvalidRequest, false;
+    TradeNotAllowed = -3004, OrderRejected, false;
+    TransferOutNotAllowed = -3005, OrderRejected, false;
+    ExceedMaxBorrowable = -3006, InsufficientBalance, false;
+    HasPendingTransaction = -3007, OrderRejected, true;
+    BorrowNotAllowed = -3008, OrderRejected, false;
+    AssetNotMortgageable = -3009, OrderRejected, false;
+    RepayNotAllowed = -3010, OrderRejected, false;
+    BadDateRange = -3011, InvalidRequest, false;
+    AssetAdminBanBorrow = -3012, OrderRejected, false;
+    LtBorrowAmount = -3013, FilterFailure, false;
+    AccountBanBorrow = -3014, OrderRejected, false;
+    RepayExceedLiability = -3015, InvalidRequest, false;
+    LtMinRepay = -3016, FilterFailure, false;
+    AssetAdminBanMortgage = -3017, OrderRejected, false;
+    AccountBanMortgage = -3018, OrderRejected, false;
+    AccountBanRollout = -3019, OrderRejected, false;
+    ExceedMaxRollout = -3020, InsufficientBalance, false;
+    PairAdminBanTrade = -3021, OrderRejected, false;
+    AccountBanTrade = -3022, OrderRejected, false;
+    WarningMarginLevel = -3023, InsufficientBalance, false;
+    FewLiabilityLeft = -3024, InvalidRequest, false;
+    InvalidEffectiveTime = -3025, InvalidRequest, false;
+    ValidationFailed = -3026, InvalidRequest, false;
+    NotValidMarginAsset = -3027, InvalidRequest, false;
+    NotValidMarginPair = -3028, InvalidRequest, false;
+    TransferFailed = -3029, OrderRejected, false;
+    AccountBanRepay = -3036, OrderRejected, false;
+    PnlClearing = -3037, Server, true;
+    ListenKeyNotFound = -3038, InvalidRequest, false;
+    BalanceNotCleared = -3041, InsufficientBalance, false;
+    PriceIndexNotFound = -3042, InvalidRequest, false;
+    TransferInNotAllowed = -3043, OrderRejected, false;
+    SystemBusy = -3044, Server, true;
+    /// Binance does not hold enough of the asset right now.
+    SystemAssetInsufficient = -3045, Server, true;
+    NotWhitelistUser = -3999, Auth, false;
+
+    // 40xx - futures filters and order issues
+    InvalidOrderStatus = -4000, OrderRejected, false;
+    PriceLessThanZero = -4001, FilterFailure, false;
+    PriceGreaterThanMaxPrice = -4002, FilterFailure, false;
+    QtyLessThanZero = -4003, FilterFailure, false;
+    QtyLessThanMinQty = -4004, FilterFailure, false;
+    QtyGreaterThanMaxQty = -4005, FilterFailure, false;
+    StopPriceLessThanZero = -4006, FilterFailure, false;
+    StopPriceGreaterThanMaxPrice = -4007, FilterFailure, false;
+    TickSizeLessThanZero = -4008, FilterFailure, false;
+    MaxPriceLessThanMinPrice = -4009, FilterFailure, false;
+    MaxQtyLessThanMinQty = -4010, FilterFailure, false;
+    StepSizeLessThanZero = -4011, FilterFailure, false;
+    MaxNumOrdersLessThanZero = -4012, FilterFailure, false;
+    PriceLessThanMinPrice = -4013, FilterFailure, false;
+    PriceNotIncreasedByTickSize = -4014, FilterFailure, false;
+    InvalidClOrdIdLen = -4015, InvalidRequest, false;
+    PriceHigherThanMultiplierUp = -4016, FilterFailure, false;
+    MultiplierUpLessThanZero = -4017, FilterFailure, false;
+    MultiplierDownLessThanZero = -4018, FilterFailure, false;
+    CompositeScaleOverflow = -4019, InvalidRequest, false;
+    TargetStrategyInvalid = -4020, InvalidRequest, false;
+    InvalidDepthLimit = -4021, InvalidRequest, false;
+    WrongMarketStatus = -4022, OrderRejected, false;
+    QtyNotIncreasedByStepSize = -4023, FilterFailure, false;
+    PriceLowerThanMultiplierDown = -4024, FilterFailure, false;
+    MultiplierDecimalLessThanZero = -4025, FilterFailure, false;
+    CommissionInvalid = -4026, InvalidRequest, false;
+    InvalidAccountType = -4027, InvalidRequest, false;
+    InvalidLeverage = -4028, InvalidRequest, false;
+    InvalidTickSizePrecision = -4029, FilterFailure, false;
+    InvalidStepSizePrecision = -4030, FilterFailure, false;
+    InvalidWorkingType = -4031, InvalidRequest, false;
+    ExceedMaxCancelOrderSize = -4032, InvalidRequest, false;
+    InsuranceAccountNotFound = -4033, InvalidRequest, false;
+    InvalidBalanceType = -4044, InvalidRequest, false;
+    MaxStopOrderExceeded = -4045, RateLimit, false;
+    NoNeedToChangeMarginType = -4046, InvalidRequest, false;
+    /// The margin type cannot be changed with open orders.
+    ThereExistsOpenOrders = -4047, OrderRejected, false;
+    /// The margin type cannot be changed with an open position.
+    ThereExistsQuantity = -4048, OrderRejected, false;
+    AddIsolatedMarginReject = -4049, OrderRejected, false;
+    CrossBalanceInsufficient = -4050, InsufficientBalance, false;
+    IsolatedBalanceInsufficient = -4051, InsufficientBalance, false;
+    NoNeedToChangeAutoAddMargin = -4052, InvalidRequest, false;
+    AutoAddCrossedMarginReject = -4053, OrderRejected, false;
+    AddIsolatedMarginNoPositionReject = -4054, OrderRejected, false;
+    AmountMustBePositive = -4055, InvalidRequest, false;
+    InvalidApiKeyType = -4056, Auth, false;
+    InvalidRsaPublicKey = -4057, Auth, false;
+    MaxPriceTooLarge = -4058, FilterFailure, false;
+    NoNeedToChangePositionSide = -4059, InvalidRequest, false;
+    InvalidPositionSide = -4060, InvalidRequest, false;
+    PositionSideNotMatch = -4061, InvalidRequest, false;
+    ReduceOnlyConflict = -4062, InvalidRequest, false;
+    PositionSideChangeExistsOpenOrders = -4067, OrderRejected, false;
+    PositionSideChangeExistsQuantity = -4068, OrderRejected, false;
+    InvalidBatchPlaceOrderSize = -4082, InvalidRequest, false;
+    PlaceBatchOrdersFail = -4083, OrderRejected, false;
+    UpcomingMethod = -4084, InvalidRequest, false;
+    InvalidNotionalLimitCoef = -4085, InvalidRequest, false;
+    InvalidPriceSpreadThreshold = -4086, InvalidRequest, false;
+    ReduceOnlyOrderPermission = -4087, Auth, false;
+    NoPlaceOrderPermission = -4088, Auth, false;
+    InvalidContractType = -4104, InvalidRequest, false;
+    InvalidClientTranIdLen = -4114, InvalidRequest, false;
+    DuplicatedClientTranId = -4115, InvalidRequest, false;
+    ReduceOnlyMarginCheckFailed = -4118, InsufficientBalance, false;
+    MarketOrderReject = -4131, FilterFailure, false;
+    InvalidActivationPrice = -4135, FilterFailure, false;
+    QuantityExistsWithClosePosition = -4137, InvalidRequest, false;
+    ReduceOnlyMustBeTrue = -4138, InvalidRequest, false;
+    OrderTypeCannotBeMkt = -4139, InvalidRequest, false;
+    InvalidOpeningPositionStatus = -4140, OrderRejected, false;
+    SymbolAlreadyClosed = -4141, OrderRejected, false;
+    StrategyInvalidTriggerPrice = -4142, FilterFailure, false;
+    InvalidPair = -4144, InvalidRequest, false;
+    IsolatedLeverageRejectWithPosition = -4161, OrderRejected, false;
+    MinNotional = -4164, FilterFailure, false;
+    InvalidTimeInterval = -4165, InvalidRequest, false;
+    PriceHigherThanStopMultiplierUp = -4183, FilterFailure, false;
+    PriceLowerThanStopMultiplierDown = -4184, FilterFailure, false;
+
+    // 50xx - futures order rejections
+    FokOrderReject = -5021, OrderRejected, false;
+    GtxOrderReject = -5022, OrderRejected, false;
+    MoveOrderNotAllowedSymbolReason = -5024, OrderRejected, false;
+    LimitOrderOnly = -5025, InvalidRequest, false;
+    ExceedMaximumModifyOrderLimit = -5026, RateLimit, false;
+    SameOrder = -5027, InvalidRequest, false;
+    /// The timestamp is outside of the `recvWindow` of the matching engine.
+    MeRecvWindowReject = -5028, Timestamp, true;
+    InvalidPriceMatch = -5037, InvalidRequest, false;
+    UnsupportedOrderTypePriceMatch = -5038, InvalidRequest, false;
+    InvalidSelfTradePreventionMode = -5039, InvalidRequest, false;
+    FutureGoodTillDate = -5040, InvalidRequest, false;
+    BboOrderReject = -5041, OrderRejected, false;
+}
+futures {
+    BadAsset = -1108, InvalidRequest, false;
+    OrderWouldImmediatelyTrigger = -2021, OrderRejected, false;
+    ReduceOnlyRejected = -2022, OrderRejected, false;
+    ReduceOnlyOrderTypeNotSupported = -2026, InvalidRequest, false;
+}
+}
+
+impl ErrorCode {
+    /// Category refined by the error message.
+    ///
+    /// Spot reports insufficient balance, filter failures and unknown orders
+    /// with the generic `-2010`/`-2011` codes and tells them apart only in `msg`.
+    pub fn category_with_msg(&self, msg: &str) -> ErrorCategory {
+        match self {
+            ErrorCode::NewOrderRejected | ErrorCode::CancelRejected => {
+                if msg.contains("insufficient balance") {
+                    ErrorCategory::InsufficientBalance
+                } else if msg.starts_with("Filter failure") {
+                    ErrorCategory::FilterFailure
+                } else if msg == "Unknown order sent." || msg == "Order does not exist." {
+                    ErrorCategory::UnknownOrder
+                } else {
+                    ErrorCategory::OrderRejected
+                }
+            }
+            code => code.category(),
+        }
+    }
+}
+
+impl From<i64> for ErrorCode {
+    #[inline]
+    fn from(code: i64) -> Self {
+        ErrorCode::from_code(code)
+    }
+}
+
+impl From<ErrorCode> for i64 {
+    #[inline]
+    fn from(code: ErrorCode) -> Self {
+        code.code()
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_code_round_trip() {
+        for code in [-1003, -1021, -2010, -2011, -4164] {
+            assert_eq!(ErrorCode::from_code(code).code(), code);
+        }
+        assert_eq!(ErrorCode::from_code(-1003), ErrorCode::TooManyRequests);
+        assert_eq!(ErrorCode::from_code(-9999), ErrorCode::Unknown(-9999));
+        assert_eq!(ErrorCode::Unknown(-9999).code(), -9999);
+
+        let code: ErrorCode = serde_json::from_str("-1021").unwrap();
+        assert_eq!(code, ErrorCode::InvalidTimestamp);
+        assert_eq!(serde_json::to_string(&code).unwrap(), "-1021");
+    }
+
+    #[test]
+    fn test_error_code_by_market() {
+        for (code, spot, futures) in [
+            (-1108, ErrorCode::ParamOverflow, ErrorCode::BadAsset),
+            (-2021, ErrorCode::OrderCancelReplacePartiallyFailed, ErrorCode::OrderWouldImmediatelyTrigger),
+            (-2022, ErrorCode::OrderCancelReplaceFailed, ErrorCode::ReduceOnlyRejected),
+            (-2026, ErrorCode::OrderArchived, ErrorCode::ReduceOnlyOrderTypeNotSupported),
+        ] {
+            assert_eq!(ErrorCode::from_code(code), spot);
+            assert_eq!(ErrorCode::from_code_in(code, Market::Spot), spot);
+            assert_eq!(ErrorCode::from_code_in(code, Market::Futures), futures);
+            assert_eq!(futures.code(), code);
+        }
+        assert_eq!(ErrorCode::OrderArchived.category(), ErrorCategory::UnknownOrder);
+        assert_eq!(ErrorCode::ReduceOnlyOrderTypeNotSupported.category(), ErrorCategory::InvalidRequest);
+        assert_eq!(ErrorCode::from_code_in(-4047, Market::Futures), ErrorCode::ThereExistsOpenOrders);
+        assert_eq!(ErrorCode::from_code_in(-3006, Market::Spot).category(), ErrorCategory::InsufficientBalance);
+        assert_eq!(ErrorCode::from_code_in(-9999, Market::Futures), ErrorCode::Unknown(-9999));
+    }
+
+    #[test]
+    fn test_error_code_classification() {
+        assert_eq!(ErrorCode::TooManyRequests.category(), ErrorCategory::RateLimit);
+        assert!(ErrorCode::TooManyRequests.is_retryable());
+        assert_eq!(ErrorCode::InvalidTimestamp.category(), ErrorCategory::Timestamp);
+        assert!(ErrorCode::InvalidTimestamp.is_retryable());
+        assert_eq!(ErrorCode::InvalidSignature.category(), ErrorCategory::Auth);
+        assert!(!ErrorCode::InvalidSignature.is_retryable());
+        assert_eq!(ErrorCode::Unknown(-9999).category(), ErrorCategory::Unknown);
+        assert!(!ErrorCode::Unknown(-9999).is_retryable());
+        assert_eq!(ErrorCode::Timeout.category(), ErrorCategory::Server);
+        assert!(!ErrorCode::Timeout.is_retryable());
+        assert!(ErrorCode::Timeout.is_execution_status_unknown());
+        assert!(!ErrorCode::ServerBusy.is_execution_status_unknown());
+
+        assert_eq!(
+            ErrorCode::NewOrderRejected
+                .category_with_msg("Account has insufficient balance for requested action."),
+            ErrorCategory::InsufficientBalance,
+        );
+        assert_eq!(
+            ErrorCode::NewOrderRejected.category_with_msg("Filter failure: LOT_SIZE"),
+            ErrorCategory::FilterFailure,
+        );
+        assert_eq!(
+            ErrorCode::CancelRejected.category_with_msg("Unknown order sent."),
+            ErrorCategory::UnknownOrder,
+        );
+        assert_eq!(
+            ErrorCode::NewOrderRejected.category_with_msg("Market is closed."),
+            ErrorCategory::OrderRejected,
+        );
+    }
+}
@@ -1,4 +1,6 @@
+pub mod error;
 pub mod futures_common;
 pub mod futures_dapi;
 pub mod futures_fapi;
 pub mod spot;
+pub mod time;
@@ -0,0 +1,227 @@
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use strum_macros::{EnumString, IntoStaticStr};
+
+mod de;
+
+pub use de::{deserialize_in, TimeUnitSeed};
+
+/// Resolution of the timestamps of the responses, selected with the `X-MBX-TIME-UNIT` header.
+#[rustfmt::skip]
+#[derive(
+    Copy, Clone, Debug, Default, Eq, PartialEq, Hash,
+    Deserialize, Serialize,
+    EnumString, IntoStaticStr,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum TimeUnit {
+    #[default]
+    Millisecond,
+    Microsecond,
+}
+
+impl TimeUnit {
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        self.into()
+    }
+}
+
+impl std::fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// Unix time in the unit it was received in.
+///
+/// Timestamps compare and hash by the instant they denote, whatever their unit.
+/// They are read in milliseconds unless deserialized with [`deserialize_in`].
+/// It is serialized back as the bare number.
+#[derive(Copy, Clone, Debug)]
+pub struct Timestamp {
+    value: u64,
+    unit: TimeUnit,
+}
+
+impl Timestamp {
+    #[inline]
+    pub const fn new(value: u64, unit: TimeUnit) -> Self {
+        Timestamp { value, unit }
+    }
+
+    #[inline]
+    pub const fn from_millis(millis: u64) -> Self {
+        Timestamp::new(millis, TimeUnit::Millisecond)
+    }
+
+    #[inline]
+    pub const fn from_micros(micros: u64) -> Self {
+        Timestamp::new(micros, TimeUnit::Microsecond)
+    }
+
+    /// The number as received, in [`unit`](Self::unit).
+    #[inline]
+    pub const fn value(&self) -> u64 {
+        self.value
+    }
+
+    #[inline]
+    pub const fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    /// Milliseconds, truncating the microseconds.
+    #[inline]
+    pub const fn as_millis(&self) -> u64 {
+        match self.unit {
+            TimeUnit::Millisecond => self.value,
+            TimeUnit::Microsecond => self.value / 1000,
+        }
+    }
+
+    /// Microseconds, saturating at `u64::MAX`.
+    #[inline]
+    pub const fn as_micros(&self) -> u64 {
+        match self.unit {
+            TimeUnit::Millisecond => self.value.saturating_mul(1000),
+            TimeUnit::Microsecond => self.value,
+        }
+    }
+
+    #[inline]
+    pub fn to_system_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_micros(self.as_micros())
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_micros() == other.as_micros()
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_micros().cmp(&other.as_micros())
+    }
+}
+
+impl std::hash::Hash for Timestamp {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_micros().hash(state)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(de::TIMESTAMP, TimestampVisitor)
+    }
+}
+
+struct TimestampVisitor;
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a unix timestamp")
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Timestamp, E> {
+        Ok(Timestamp::from_millis(value))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Timestamp, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u64::deserialize(deserializer).map(Timestamp::from_millis)
+    }
+
+    // the value and the unit, from `deserialize_in`
+    fn visit_seq<A>(self, mut seq: A) -> Result<Timestamp, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let value = seq.next_element()?.ok_or_else(|| Error::invalid_length(0, &self))?;
+        let unit = seq.next_element()?.ok_or_else(|| Error::invalid_length(1, &self))?;
+        Ok(Timestamp::new(value, unit))
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct SomeData {
+        pub transact_time: Timestamp,
+    }
+
+    #[test]
+    fn test_deserialize_timestamp_in_unit() {
+        let json_ms = r#"{"transactTime":1507725176595}"#;
+        let data_ms: SomeData = serde_json::from_str(json_ms).unwrap();
+        assert_eq!(data_ms.transact_time.unit(), TimeUnit::Millisecond);
+        assert_eq!(data_ms.transact_time.as_micros(), 1507725176595000);
+
+        let json_us = r#"{"transactTime":1507725176595123}"#;
+        let mut deserializer = serde_json::Deserializer::from_str(json_us);
+        let data_us: SomeData = deserialize_in(&mut deserializer, TimeUnit::Microsecond).unwrap();
+        assert_eq!(data_us.transact_time.unit(), TimeUnit::Microsecond);
+        assert_eq!(data_us.transact_time.as_millis(), 1507725176595);
+
+        assert!(data_ms.transact_time < data_us.transact_time);
+        assert_eq!(serde_json::to_string(&data_us).unwrap(), json_us);
+    }
+
+    #[test]
+    fn test_timestamps_compare_across_units() {
+        assert_eq!(Timestamp::from_millis(1499827319559), Timestamp::from_micros(1499827319559000));
+        assert_ne!(Timestamp::from_millis(1499827319559), Timestamp::from_micros(1499827319559001));
+        assert_eq!(TimeUnit::Microsecond.as_str(), "MICROSECOND");
+        assert_eq!(Timestamp::from_millis(u64::MAX).as_micros(), u64::MAX);
+    }
+
+    #[test]
+    fn test_deserialize_nested_timestamps_in_unit() {
+        #[derive(Deserialize)]
+        struct Nested {
+            times: Vec<Option<Timestamp>>,
+            data: Option<SomeData>,
+        }
+
+        let json = r#"{"times":[1507725176595123,null],"data":{"transactTime":1507725176595123}}"#;
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        let nested: Nested = deserialize_in(&mut deserializer, TimeUnit::Microsecond).unwrap();
+        assert_eq!(nested.times[0].unwrap().unit(), TimeUnit::Microsecond);
+        assert_eq!(nested.times[1], None);
+        assert_eq!(nested.data.unwrap().transact_time.unit(), TimeUnit::Microsecond);
+    }
+}
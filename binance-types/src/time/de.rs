@@ -0,0 +1,368 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{
+    DeserializeSeed, Deserializer, EnumAccess, Error, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde::Deserialize;
+
+use super::TimeUnit;
+
+/// Newtype name of [`Timestamp`](super::Timestamp), answered with the value and the unit.
+pub(super) const TIMESTAMP: &str = "$binancex::Timestamp";
+
+/// Deserializes `T` with the timestamps within read in `unit`.
+///
+/// JSON numbers carry no unit, so the timestamps of a response requested in microseconds
+/// have to be deserialized this way. Elsewhere they are read in milliseconds.
+///
+/// Values buffered by serde, e.g. within untagged enums or flattened structs, lose the unit.
+pub fn deserialize_in<'de, T, D>(deserializer: D, unit: TimeUnit) -> Result<T, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    TimeUnitSeed::new(unit).deserialize(deserializer)
+}
+
+/// [`DeserializeSeed`] of `T` with the timestamps within read in the unit, see [`deserialize_in`].
+pub struct TimeUnitSeed<T> {
+    unit: TimeUnit,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> TimeUnitSeed<T> {
+    #[inline]
+    pub fn new(unit: TimeUnit) -> Self {
+        TimeUnitSeed {
+            unit,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for TimeUnitSeed<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TimeUnitSeed<T> {}
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for TimeUnitSeed<T> {
+    type Value = T;
+
+    #[inline]
+    fn deserialize<D>(self, deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(Wrap::new(deserializer, self.unit))
+    }
+}
+
+/// Deserializer, visitor, seed or access passing the unit down to the values within.
+struct Wrap<X> {
+    inner: X,
+    unit: TimeUnit,
+}
+
+impl<X> Wrap<X> {
+    #[inline]
+    fn new(inner: X, unit: TimeUnit) -> Self {
+        Wrap { inner, unit }
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            #[inline]
+            fn $method<V>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.inner.$method($($arg,)* Wrap::new(visitor, self.unit))
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Wrap<D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any();
+        deserialize_bool();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_i128();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_u128();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_option();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_seq();
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map();
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier();
+        deserialize_ignored_any();
+    }
+
+    fn deserialize_newtype_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if name == TIMESTAMP {
+            let value = u64::deserialize(self.inner)?;
+            return visitor.visit_seq(TimestampAccess {
+                value: Some(value),
+                unit: Some(self.unit),
+                marker: PhantomData,
+            });
+        }
+        self.inner
+            .deserialize_newtype_struct(name, Wrap::new(visitor, self.unit))
+    }
+
+    #[inline]
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            #[inline]
+            fn $method<E: Error>(self, value: $ty) -> Result<V::Value, E> {
+                self.inner.$method(value)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for Wrap<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(bool);
+        visit_i8(i8);
+        visit_i16(i16);
+        visit_i32(i32);
+        visit_i64(i64);
+        visit_i128(i128);
+        visit_u8(u8);
+        visit_u16(u16);
+        visit_u32(u32);
+        visit_u64(u64);
+        visit_u128(u128);
+        visit_f32(f32);
+        visit_f64(f64);
+        visit_char(char);
+        visit_str(&str);
+        visit_borrowed_str(&'de str);
+        visit_string(String);
+        visit_bytes(&[u8]);
+        visit_borrowed_bytes(&'de [u8]);
+        visit_byte_buf(Vec<u8>);
+    }
+
+    #[inline]
+    fn visit_none<E: Error>(self) -> Result<V::Value, E> {
+        self.inner.visit_none()
+    }
+
+    #[inline]
+    fn visit_unit<E: Error>(self) -> Result<V::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    #[inline]
+    fn visit_some<D>(self, deserializer: D) -> Result<V::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner.visit_some(Wrap::new(deserializer, self.unit))
+    }
+
+    #[inline]
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<V::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner
+            .visit_newtype_struct(Wrap::new(deserializer, self.unit))
+    }
+
+    #[inline]
+    fn visit_seq<A>(self, seq: A) -> Result<V::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.inner.visit_seq(Wrap::new(seq, self.unit))
+    }
+
+    #[inline]
+    fn visit_map<A>(self, map: A) -> Result<V::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.inner.visit_map(Wrap::new(map, self.unit))
+    }
+
+    #[inline]
+    fn visit_enum<A>(self, data: A) -> Result<V::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        self.inner.visit_enum(Wrap::new(data, self.unit))
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Wrap<S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn deserialize<D>(self, deserializer: D) -> Result<S::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner.deserialize(Wrap::new(deserializer, self.unit))
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for Wrap<A> {
+    type Error = A::Error;
+
+    #[inline]
+    fn next_element_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, A::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        self.inner.next_element_seed(Wrap::new(seed, self.unit))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for Wrap<A> {
+    type Error = A::Error;
+
+    #[inline]
+    fn next_key_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, A::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        self.inner.next_key_seed(seed)
+    }
+
+    #[inline]
+    fn next_value_seed<S>(&mut self, seed: S) -> Result<S::Value, A::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        self.inner.next_value_seed(Wrap::new(seed, self.unit))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: EnumAccess<'de>> EnumAccess<'de> for Wrap<A> {
+    type Error = A::Error;
+    type Variant = Wrap<A::Variant>;
+
+    #[inline]
+    fn variant_seed<S>(self, seed: S) -> Result<(S::Value, Wrap<A::Variant>), A::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let (value, variant) = self.inner.variant_seed(seed)?;
+        Ok((value, Wrap::new(variant, self.unit)))
+    }
+}
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for Wrap<A> {
+    type Error = A::Error;
+
+    #[inline]
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.inner.unit_variant()
+    }
+
+    #[inline]
+    fn newtype_variant_seed<S>(self, seed: S) -> Result<S::Value, A::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        self.inner.newtype_variant_seed(Wrap::new(seed, self.unit))
+    }
+
+    #[inline]
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, A::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner.tuple_variant(len, Wrap::new(visitor, self.unit))
+    }
+
+    #[inline]
+    fn struct_variant<V>(self, fields: &'static [&'static str], visitor: V) -> Result<V::Value, A::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner.struct_variant(fields, Wrap::new(visitor, self.unit))
+    }
+}
+
+/// The value of a timestamp followed by its unit.
+struct TimestampAccess<E> {
+    value: Option<u64>,
+    unit: Option<TimeUnit>,
+    marker: PhantomData<E>,
+}
+
+impl<'de, E: Error> SeqAccess<'de> for TimestampAccess<E> {
+    type Error = E;
+
+    fn next_element_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, E>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        if let Some(value) = self.value.take() {
+            return seed.deserialize(IntoDeserializer::<E>::into_deserializer(value)).map(Some);
+        }
+        if let Some(unit) = self.unit.take() {
+            return seed
+                .deserialize(IntoDeserializer::<E>::into_deserializer(unit.as_str()))
+                .map(Some);
+        }
+        Ok(None)
+    }
+}
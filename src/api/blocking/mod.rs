@@ -0,0 +1,66 @@
+//! Synchronous clients, for code that does not run a tokio runtime.
+//!
+//! Every client mirrors the methods of its async counterpart and blocks the calling thread
+//! until the response is received. The requests are driven by a runtime shared by all blocking
+//! clients, started with the first one. Background tasks, e.g. the server time sync, run on it
+//! between calls too.
+//!
+//! The methods panic if called from within an async runtime, use the async clients there.
+//!
+//! ```no_run
+//! use binancex::blocking::BinanceSpot;
+//!
+//! let spot = BinanceSpot::signed("api_key", "secret_key").unwrap();
+//! let account = spot.get_account_info().unwrap();
+//! ```
+
+use std::future::Future;
+use std::sync::OnceLock;
+
+use tokio::runtime::Runtime;
+
+/// Blocking versions of the async methods, with the same signatures.
+macro_rules! blocking_methods {
+    ($async_client:ident {
+        $(
+            $(#[$attr:meta])*
+            fn $name:ident(&self $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty;
+        )*
+    }) => {
+        $(
+            $(#[$attr])*
+            // the same error as the async method
+            #[allow(clippy::result_large_err)]
+            #[doc = concat!("Blocking [`", stringify!($async_client), "::", stringify!($name), "`](crate::", stringify!($async_client), "::", stringify!($name), ").")]
+            pub fn $name(&self $(, $arg: $ty)*) -> $ret {
+                crate::api::blocking::block_on(self.inner.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+pub mod delivery_futures;
+pub mod perpetual_futures;
+pub mod spot;
+
+pub use delivery_futures::BinanceDeliveryFutures;
+pub use perpetual_futures::BinancePerpFutures;
+pub use spot::BinanceSpot;
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+/// The runtime of the blocking clients, a single worker is enough to drive the connections.
+pub(crate) fn runtime() -> &'static Runtime {
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("binancex-blocking")
+            .enable_all()
+            .build()
+            .expect("cannot start the runtime of the blocking client")
+    })
+}
+
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    runtime().block_on(future)
+}
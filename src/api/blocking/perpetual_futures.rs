@@ -0,0 +1,176 @@
+use crate::api::blocking::{block_on, runtime};
+use crate::api::client::credentials::SecretString;
+use crate::api::client::error::BinanceError;
+use crate::api::client::hosts::HostPool;
+use crate::api::client::options::RequestOptions;
+use crate::api::client::proxy::ProxyConfig;
+use crate::api::client::rate_limit::{RateLimiter, WeightLimit};
+use crate::api::client::response::Response;
+use crate::api::client::source_ip::SourceIpPool;
+use crate::api::client::time_sync::TimeOffset;
+use crate::api::client::{BinanceClient, BinanceClientBuilder};
+use crate::api::perpetual_futures;
+use crate::api::{BinanceBuilder, FromBinanceClient};
+use crate::schemes::common::{Pong, ServerTime};
+use crate::schemes::error::FuturesError;
+use crate::schemes::futures_fapi::general::ExchangeInfo;
+use crate::schemes::futures_fapi::market::*;
+use crate::types::futures_fapi::limits::DepthLimit;
+
+/// Blocking [`BinancePerpFutures`](crate::BinancePerpFutures).
+#[derive(Clone)]
+pub struct BinancePerpFutures {
+    inner: perpetual_futures::BinancePerpFutures,
+}
+
+impl BinancePerpFutures {
+    pub fn builder() -> BinanceBuilder<Self> {
+        BinanceBuilder::new()
+    }
+
+    pub fn new() -> Result<Self, BinanceError<()>> {
+        Ok(Self {
+            inner: perpetual_futures::BinancePerpFutures::new()?,
+        })
+    }
+
+    pub fn signed<K1, K2>(api_key: K1, secret_key: K2) -> Result<Self, BinanceError<()>>
+    where
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
+    {
+        Ok(Self {
+            inner: perpetual_futures::BinancePerpFutures::signed(api_key, secret_key)?,
+        })
+    }
+
+    pub fn testnet<K1, K2>(api_key: K1, secret_key: K2) -> Result<Self, BinanceError<()>>
+    where
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
+    {
+        Ok(Self {
+            inner: perpetual_futures::BinancePerpFutures::testnet(api_key, secret_key)?,
+        })
+    }
+
+    pub fn with_host<S: Into<String>>(host: S) -> Result<Self, BinanceError<()>> {
+        Ok(Self {
+            inner: perpetual_futures::BinancePerpFutures::with_host(host)?,
+        })
+    }
+
+    pub fn signed_with_host<S, K1, K2>(
+        host: S,
+        api_key: K1,
+        secret_key: K2,
+    ) -> Result<Self, BinanceError<()>>
+    where
+        S: Into<String>,
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
+    {
+        Ok(Self {
+            inner: perpetual_futures::BinancePerpFutures::signed_with_host(
+                host, api_key, secret_key,
+            )?,
+        })
+    }
+
+    /// The async client, sharing the connections and the rate limiters of this one.
+    pub fn as_async(&self) -> &perpetual_futures::BinancePerpFutures {
+        &self.inner
+    }
+
+    blocking_methods!(BinancePerpFutures {
+        fn sync_server_time(&self) -> Result<TimeOffset, BinanceError<FuturesError>>;
+        fn sync_rate_limits(&self) -> Result<(), BinanceError<FuturesError>>;
+        fn warm_up(&self, connections: usize) -> usize;
+    });
+
+    /// Blocking [`BinancePerpFutures::check_hosts`](crate::BinancePerpFutures::check_hosts).
+    pub fn check_hosts(&self) {
+        block_on(self.inner.check_hosts())
+    }
+
+    /// Last measured server clock offset and round-trip time.
+    pub fn time_offset(&self) -> TimeOffset {
+        self.inner.time_offset()
+    }
+
+    /// Client side weight accounting, if enabled with `BinanceBuilder::rate_limiter`.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.inner.rate_limiter()
+    }
+
+    /// Order count of the account, if enabled with `BinanceBuilder::order_rate_limiter`.
+    pub fn order_rate_limiter(&self) -> Option<&RateLimiter> {
+        self.inner.order_rate_limiter()
+    }
+
+    /// Local addresses set with `BinanceBuilder::bind_local_addresses` and their weight accounting.
+    pub fn source_ip_pool(&self) -> Option<&SourceIpPool> {
+        self.inner.source_ip_pool()
+    }
+
+    /// Proxy set with `BinanceBuilder::proxy`.
+    pub fn proxy(&self) -> Option<&ProxyConfig> {
+        self.inner.proxy()
+    }
+
+    /// Hosts of the client, if several are set with `BinanceBuilder::hosts`.
+    pub fn host_pool(&self) -> Option<&HostPool> {
+        self.inner.host_pool()
+    }
+
+    blocking_methods!(BinancePerpFutures {
+        fn ping(&self) -> Result<Response<Pong>, BinanceError<FuturesError>>;
+        fn ping_with(
+            &self,
+            opts: &RequestOptions,
+        ) -> Result<Response<Pong>, BinanceError<FuturesError>>;
+        fn get_server_time(&self) -> Result<Response<ServerTime>, BinanceError<FuturesError>>;
+        fn get_server_time_with(
+            &self,
+            opts: &RequestOptions,
+        ) -> Result<Response<ServerTime>, BinanceError<FuturesError>>;
+        fn get_exchange_info(&self) -> Result<Response<ExchangeInfo>, BinanceError<FuturesError>>;
+        fn get_exchange_info_with(
+            &self,
+            opts: &RequestOptions,
+        ) -> Result<Response<ExchangeInfo>, BinanceError<FuturesError>>;
+        fn get_depth(&self, symbol: &str, limit: DepthLimit) -> Result<Response<OrderBook>, BinanceError<FuturesError>>;
+        fn get_depth_with(
+            &self,
+            symbol: &str,
+            limit: DepthLimit,
+            opts: &RequestOptions,
+        ) -> Result<Response<OrderBook>, BinanceError<FuturesError>>;
+    });
+}
+
+impl FromBinanceClient for BinancePerpFutures {
+    const HOST: &'static str = perpetual_futures::BinancePerpFutures::HOST;
+    const SERVER_TIME_PATH: &'static str = perpetual_futures::BinancePerpFutures::SERVER_TIME_PATH;
+    const PING_PATH: &'static str = perpetual_futures::BinancePerpFutures::PING_PATH;
+    const REQUEST_WEIGHT_LIMIT_1M: u32 =
+        perpetual_futures::BinancePerpFutures::REQUEST_WEIGHT_LIMIT_1M;
+    const ORDER_LIMITS: &'static [WeightLimit] =
+        perpetual_futures::BinancePerpFutures::ORDER_LIMITS;
+
+    fn from_client(client: BinanceClient) -> Self {
+        Self {
+            inner: perpetual_futures::BinancePerpFutures::from_client(client),
+        }
+    }
+
+    fn client(&self) -> &BinanceClient {
+        self.inner.client()
+    }
+
+    fn build(builder: BinanceClientBuilder) -> Result<Self, BinanceError<()>> {
+        // background tasks are spawned on the runtime of the blocking clients
+        let _guard = runtime().enter();
+        Ok(Self::from_client(builder.build()?))
+    }
+}
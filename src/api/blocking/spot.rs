@@ -0,0 +1,480 @@
+use bytes::Bytes;
+
+use crate::api::blocking::{block_on, runtime};
+use crate::api::client::credentials::SecretString;
+use crate::api::client::error::BinanceError;
+use crate::api::client::hosts::HostPool;
+use crate::api::client::options::RequestOptions;
+use crate::api::client::proxy::ProxyConfig;
+use crate::api::client::rate_limit::{RateLimiter, WeightLimit};
+use crate::api::client::response::Response;
+use crate::api::client::source_ip::SourceIpPool;
+use crate::api::client::time_sync::TimeOffset;
+use crate::api::client::{BinanceClient, BinanceClientBuilder};
+use crate::api::spot;
+use crate::api::{BinanceBuilder, FromBinanceClient};
+use crate::schemes::common::{Pong, ServerTime};
+use crate::schemes::error::{CancelReplaceOrderError, Error};
+use crate::schemes::spot::general::ExchangeInfo;
+use crate::schemes::spot::market::*;
+use crate::schemes::spot::trade::*;
+use crate::types::spot::limits::{DepthLimit, KLinesInterval};
+use crate::types::time::TimeUnit;
+
+/// Blocking [`BinanceSpot`](crate::BinanceSpot).
+#[derive(Clone)]
+pub struct BinanceSpot {
+    inner: spot::BinanceSpot,
+}
+
+impl BinanceSpot {
+    pub fn builder() -> BinanceBuilder<Self> {
+        BinanceBuilder::new()
+    }
+
+    pub fn new() -> Result<Self, BinanceError<()>> {
+        Ok(Self {
+            inner: spot::BinanceSpot::new()?,
+        })
+    }
+
+    pub fn signed<K1, K2>(api_key: K1, secret_key: K2) -> Result<Self, BinanceError<()>>
+    where
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
+    {
+        Ok(Self {
+            inner: spot::BinanceSpot::signed(api_key, secret_key)?,
+        })
+    }
+
+    pub fn testnet<K1, K2>(api_key: K1, secret_key: K2) -> Result<Self, BinanceError<()>>
+    where
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
+    {
+        Ok(Self {
+            inner: spot::BinanceSpot::testnet(api_key, secret_key)?,
+        })
+    }
+
+    pub fn with_host<S: Into<String>>(host: S) -> Result<Self, BinanceError<()>> {
+        Ok(Self {
+            inner: spot::BinanceSpot::with_host(host)?,
+        })
+    }
+
+    pub fn signed_with_host<S, K1, K2>(
+        host: S,
+        api_key: K1,
+        secret_key: K2,
+    ) -> Result<Self, BinanceError<()>>
+    where
+        S: Into<String>,
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
+    {
+        Ok(Self {
+            inner: spot::BinanceSpot::signed_with_host(host, api_key, secret_key)?,
+        })
+    }
+
+    /// The async client, sharing the connections and the rate limiters of this one.
+    pub fn as_async(&self) -> &spot::BinanceSpot {
+        &self.inner
+    }
+
+    blocking_methods!(BinanceSpot {
+        fn sync_server_time(&self) -> Result<TimeOffset, BinanceError<Error>>;
+        fn sync_rate_limits(&self) -> Result<(), BinanceError<Error>>;
+        fn warm_up(&self, connections: usize) -> usize;
+    });
+
+    /// Blocking [`BinanceSpot::check_hosts`](crate::BinanceSpot::check_hosts).
+    pub fn check_hosts(&self) {
+        block_on(self.inner.check_hosts())
+    }
+
+    /// Last measured server clock offset and round-trip time.
+    pub fn time_offset(&self) -> TimeOffset {
+        self.inner.time_offset()
+    }
+
+    /// Client side weight accounting, if enabled with `BinanceBuilder::rate_limiter`.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.inner.rate_limiter()
+    }
+
+    /// Order count of the account, if enabled with `BinanceBuilder::order_rate_limiter`.
+    pub fn order_rate_limiter(&self) -> Option<&RateLimiter> {
+        self.inner.order_rate_limiter()
+    }
+
+    /// Local addresses set with `BinanceBuilder::bind_local_addresses` and their weight accounting.
+    pub fn source_ip_pool(&self) -> Option<&SourceIpPool> {
+        self.inner.source_ip_pool()
+    }
+
+    /// Proxy set with `BinanceBuilder::proxy`.
+    pub fn proxy(&self) -> Option<&ProxyConfig> {
+        self.inner.proxy()
+    }
+
+    /// Hosts of the client, if several are set with `BinanceBuilder::hosts`.
+    pub fn host_pool(&self) -> Option<&HostPool> {
+        self.inner.host_pool()
+    }
+
+    /// Resolution of the response timestamps, set with `BinanceBuilder::time_unit`.
+    pub fn time_unit(&self) -> TimeUnit {
+        self.inner.time_unit()
+    }
+
+    blocking_methods!(BinanceSpot {
+        fn ping(&self) -> Result<Response<Pong>, BinanceError<Error>>;
+        fn ping_with(
+            &self,
+            opts: &RequestOptions,
+        ) -> Result<Response<Pong>, BinanceError<Error>>;
+        fn get_server_time(&self) -> Result<Response<ServerTime>, BinanceError<Error>>;
+        fn get_server_time_with(
+            &self,
+            opts: &RequestOptions,
+        ) -> Result<Response<ServerTime>, BinanceError<Error>>;
+        fn get_exchange_info(&self) -> Result<Response<ExchangeInfo>, BinanceError<Error>>;
+        fn get_exchange_info_with(
+            &self,
+            opts: &RequestOptions,
+        ) -> Result<Response<ExchangeInfo>, BinanceError<Error>>;
+        fn get_depth(&self, symbol: &str, limit: DepthLimit) -> Result<Response<OrderBook>, BinanceError<Error>>;
+        fn get_depth_with(
+            &self,
+            symbol: &str,
+            limit: DepthLimit,
+            opts: &RequestOptions,
+        ) -> Result<Response<OrderBook>, BinanceError<Error>>;
+        fn get_depth_raw(&self, symbol: &str, limit: DepthLimit) -> Result<Response<Bytes>, BinanceError<Error>>;
+        fn get_recent_trades(
+            &self,
+            symbol: &str,
+            limit: Option<u16>,
+        ) -> Result<Response<Vec<Trade>>, BinanceError<Error>>;
+        fn get_recent_trades_with(
+            &self,
+            symbol: &str,
+            limit: Option<u16>,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<Trade>>, BinanceError<Error>>;
+        fn get_recent_trades_raw(
+            &self,
+            symbol: &str,
+            limit: Option<u16>,
+        ) -> Result<Response<Bytes>, BinanceError<Error>>;
+        fn get_historical_trades(
+            &self,
+            symbol: &str,
+            from_id: Option<u64>,
+            limit: Option<u16>,
+        ) -> Result<Response<Vec<Trade>>, BinanceError<Error>>;
+        fn get_historical_trades_with(
+            &self,
+            symbol: &str,
+            from_id: Option<u64>,
+            limit: Option<u16>,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<Trade>>, BinanceError<Error>>;
+        fn get_aggregate_trades(
+            &self,
+            symbol: &str,
+            from_id: Option<u64>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+        ) -> Result<Response<Vec<AggregateTrade>>, BinanceError<Error>>;
+        fn get_aggregate_trades_with(
+            &self,
+            symbol: &str,
+            from_id: Option<u64>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<AggregateTrade>>, BinanceError<Error>>;
+        fn get_klines(
+            &self,
+            symbol: &str,
+            interval: KLinesInterval,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+        ) -> Result<Response<Vec<KlineSummary>>, BinanceError<Error>>;
+        fn get_klines_with(
+            &self,
+            symbol: &str,
+            interval: KLinesInterval,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<KlineSummary>>, BinanceError<Error>>;
+        fn get_klines_ui(
+            &self,
+            symbol: &str,
+            interval: KLinesInterval,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+        ) -> Result<Response<Vec<KlineSummary>>, BinanceError<Error>>;
+        fn get_klines_ui_with(
+            &self,
+            symbol: &str,
+            interval: KLinesInterval,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<KlineSummary>>, BinanceError<Error>>;
+        fn get_average_price(&self, symbol: &str) -> Result<Response<AveragePrice>, BinanceError<Error>>;
+        fn get_average_price_with(
+            &self,
+            symbol: &str,
+            opts: &RequestOptions,
+        ) -> Result<Response<AveragePrice>, BinanceError<Error>>;
+        fn get_24h_ticker_full(&self, symbol: &str) -> Result<Response<TickerStatsFull>, BinanceError<Error>>;
+        fn get_24h_ticker_full_with(
+            &self,
+            symbol: &str,
+            opts: &RequestOptions,
+        ) -> Result<Response<TickerStatsFull>, BinanceError<Error>>;
+        fn get_24h_ticker_mini(&self, symbol: &str) -> Result<Response<TickerStatsMini>, BinanceError<Error>>;
+        fn get_24h_ticker_mini_with(
+            &self,
+            symbol: &str,
+            opts: &RequestOptions,
+        ) -> Result<Response<TickerStatsMini>, BinanceError<Error>>;
+        fn get_24h_tickers_full(
+            &self,
+            symbols: Option<&str>,
+        ) -> Result<Response<Vec<TickerStatsFull>>, BinanceError<Error>>;
+        fn get_24h_tickers_full_with(
+            &self,
+            symbols: Option<&str>,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<TickerStatsFull>>, BinanceError<Error>>;
+        fn get_24h_tickers_mini(
+            &self,
+            symbols: Option<&str>,
+        ) -> Result<Response<Vec<TickerStatsMini>>, BinanceError<Error>>;
+        fn get_24h_tickers_mini_with(
+            &self,
+            symbols: Option<&str>,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<TickerStatsMini>>, BinanceError<Error>>;
+        fn get_latest_price(&self, symbol: &str) -> Result<Response<SymbolPrice>, BinanceError<Error>>;
+        fn get_latest_price_with(
+            &self,
+            symbol: &str,
+            opts: &RequestOptions,
+        ) -> Result<Response<SymbolPrice>, BinanceError<Error>>;
+        fn get_latest_prices(
+            &self,
+            symbols: Option<&str>,
+        ) -> Result<Response<Vec<SymbolPrice>>, BinanceError<Error>>;
+        fn get_latest_prices_with(
+            &self,
+            symbols: Option<&str>,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<SymbolPrice>>, BinanceError<Error>>;
+        fn get_book_ticker(&self, symbol: &str) -> Result<Response<BookTicker>, BinanceError<Error>>;
+        fn get_book_ticker_with(
+            &self,
+            symbol: &str,
+            opts: &RequestOptions,
+        ) -> Result<Response<BookTicker>, BinanceError<Error>>;
+        fn get_book_ticker_raw(&self, symbol: &str) -> Result<Response<Bytes>, BinanceError<Error>>;
+        fn get_book_tickers(
+            &self,
+            symbols: Option<&str>,
+        ) -> Result<Response<Vec<BookTicker>>, BinanceError<Error>>;
+        fn get_book_tickers_with(
+            &self,
+            symbols: Option<&str>,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<BookTicker>>, BinanceError<Error>>;
+        fn get_book_tickers_raw(&self, symbols: Option<&str>) -> Result<Response<Bytes>, BinanceError<Error>>;
+    });
+
+    blocking_methods!(BinanceSpot {
+        fn test_new_order(&self, req: &NewOrderReq) -> Result<Response<TestNewOrderRes>, BinanceError<Error>>;
+        fn test_new_order_with(
+            &self,
+            req: &NewOrderReq,
+            opts: &RequestOptions,
+        ) -> Result<Response<TestNewOrderRes>, BinanceError<Error>>;
+        fn new_order(&self, req: &NewOrderReq) -> Result<Response<NewOrderRes>, BinanceError<Error>>;
+        fn new_order_with(
+            &self,
+            req: &NewOrderReq,
+            opts: &RequestOptions,
+        ) -> Result<Response<NewOrderRes>, BinanceError<Error>>;
+        fn cancel_order(&self, req: &CancelOrderReq) -> Result<Response<CancelOrderRes>, BinanceError<Error>>;
+        fn cancel_order_with(
+            &self,
+            req: &CancelOrderReq,
+            opts: &RequestOptions,
+        ) -> Result<Response<CancelOrderRes>, BinanceError<Error>>;
+        fn cancel_all_orders(&self, symbol: &str) -> Result<Response<Vec<CancelOrderRes>>, BinanceError<Error>>;
+        fn cancel_all_orders_with(
+            &self,
+            symbol: &str,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<CancelOrderRes>>, BinanceError<Error>>;
+        fn get_order_info(
+            &self,
+            symbol: &str,
+            order_id: Option<u64>,
+            orig_client_order_id: Option<String>,
+        ) -> Result<Response<OrderInfo>, BinanceError<Error>>;
+        fn get_order_info_with(
+            &self,
+            symbol: &str,
+            order_id: Option<u64>,
+            orig_client_order_id: Option<String>,
+            opts: &RequestOptions,
+        ) -> Result<Response<OrderInfo>, BinanceError<Error>>;
+        fn cancel_replace_order(
+            &self,
+            req: &CancelReplaceOrderReq,
+        ) -> Result<Response<CancelReplaceOrderRes>, BinanceError<CancelReplaceOrderError>>;
+        fn cancel_replace_order_with(
+            &self,
+            req: &CancelReplaceOrderReq,
+            opts: &RequestOptions,
+        ) -> Result<Response<CancelReplaceOrderRes>, BinanceError<CancelReplaceOrderError>>;
+        fn get_open_orders(&self, symbol: &str) -> Result<Response<Vec<OrderInfo>>, BinanceError<Error>>;
+        fn get_open_orders_with(
+            &self,
+            symbol: &str,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<OrderInfo>>, BinanceError<Error>>;
+        fn get_all_open_orders(&self) -> Result<Response<Vec<OrderInfo>>, BinanceError<Error>>;
+        fn get_all_open_orders_with(
+            &self,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<OrderInfo>>, BinanceError<Error>>;
+        fn new_oco_order(&self, req: &NewOcoOrderReq) -> Result<Response<OcoOrderRes>, BinanceError<Error>>;
+        fn new_oco_order_with(
+            &self,
+            req: &NewOcoOrderReq,
+            opts: &RequestOptions,
+        ) -> Result<Response<OcoOrderRes>, BinanceError<Error>>;
+        fn cancel_oco_order(
+            &self,
+            symbol: &str,
+            order_list_id: Option<u64>,
+            list_client_order_id: Option<String>,
+            new_client_order_id: Option<String>,
+        ) -> Result<Response<OcoOrderRes>, BinanceError<Error>>;
+        fn cancel_oco_order_with(
+            &self,
+            symbol: &str,
+            order_list_id: Option<u64>,
+            list_client_order_id: Option<String>,
+            new_client_order_id: Option<String>,
+            opts: &RequestOptions,
+        ) -> Result<Response<OcoOrderRes>, BinanceError<Error>>;
+        fn get_oco_order(&self, id: &OrderIdOrClientOrderId) -> Result<Response<OcoOrderRes>, BinanceError<Error>>;
+        fn get_oco_order_with(
+            &self,
+            id: &OrderIdOrClientOrderId,
+            opts: &RequestOptions,
+        ) -> Result<Response<OcoOrderRes>, BinanceError<Error>>;
+        fn get_all_oco_orders(
+            &self,
+            from_id: Option<u64>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+        ) -> Result<Response<Vec<OcoOrderRes>>, BinanceError<Error>>;
+        fn get_all_oco_orders_with(
+            &self,
+            from_id: Option<u64>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<OcoOrderRes>>, BinanceError<Error>>;
+        fn get_all_open_oco_orders(&self) -> Result<Response<Vec<OcoOrderRes>>, BinanceError<Error>>;
+        fn get_all_open_oco_orders_with(
+            &self,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<OcoOrderRes>>, BinanceError<Error>>;
+        fn get_all_account_orders(
+            &self,
+            symbol: &str,
+            order_id: Option<u64>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+        ) -> Result<Response<Vec<OrderInfo>>, BinanceError<Error>>;
+        fn get_all_account_orders_with(
+            &self,
+            symbol: &str,
+            order_id: Option<u64>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<OrderInfo>>, BinanceError<Error>>;
+        fn get_account_info(&self) -> Result<Response<AccountInfo>, BinanceError<Error>>;
+        fn get_account_info_with(&self, opts: &RequestOptions) -> Result<Response<AccountInfo>, BinanceError<Error>>;
+        fn get_account_trades(
+            &self,
+            req: &AccountTradesReq,
+        ) -> Result<Response<Vec<AccountTrade>>, BinanceError<Error>>;
+        fn get_account_trades_with(
+            &self,
+            req: &AccountTradesReq,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<AccountTrade>>, BinanceError<Error>>;
+        fn get_order_rate_limit(&self) -> Result<Response<Vec<OrderRateLimit>>, BinanceError<Error>>;
+        fn get_order_rate_limit_with(
+            &self,
+            opts: &RequestOptions,
+        ) -> Result<Response<Vec<OrderRateLimit>>, BinanceError<Error>>;
+    });
+}
+
+impl BinanceBuilder<BinanceSpot> {
+    /// Sends `X-MBX-TIME-UNIT` to get the response timestamps in microseconds
+    /// and sends the `timestamp` of signed requests in microseconds as well.
+    /// `recvWindow` stays in milliseconds.
+    pub fn time_unit(mut self, time_unit: TimeUnit) -> Self {
+        self.time_unit = time_unit;
+        self
+    }
+}
+
+impl FromBinanceClient for BinanceSpot {
+    const HOST: &'static str = spot::BinanceSpot::HOST;
+    const SERVER_TIME_PATH: &'static str = spot::BinanceSpot::SERVER_TIME_PATH;
+    const PING_PATH: &'static str = spot::BinanceSpot::PING_PATH;
+    const REQUEST_WEIGHT_LIMIT_1M: u32 = spot::BinanceSpot::REQUEST_WEIGHT_LIMIT_1M;
+    const ORDER_LIMITS: &'static [WeightLimit] = spot::BinanceSpot::ORDER_LIMITS;
+
+    fn from_client(client: BinanceClient) -> Self {
+        Self {
+            inner: spot::BinanceSpot::from_client(client),
+        }
+    }
+
+    fn client(&self) -> &BinanceClient {
+        self.inner.client()
+    }
+
+    fn build(builder: BinanceClientBuilder) -> Result<Self, BinanceError<()>> {
+        // background tasks are spawned on the runtime of the blocking clients
+        let _guard = runtime().enter();
+        Ok(Self::from_client(builder.build()?))
+    }
+}
@@ -0,0 +1,152 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+/// Source of the local time the client signs requests and measures the server clock offset with.
+pub trait Clock: Send + Sync {
+    /// Unix time in microseconds.
+    fn now_micros(&self) -> i64;
+
+    /// Unix time in milliseconds.
+    fn now_millis(&self) -> i64 {
+        self.now_micros().div_euclid(1000)
+    }
+}
+
+/// The system wall clock, the default.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now_micros(&self) -> i64 {
+        chrono::Utc::now().timestamp_micros()
+    }
+
+    #[inline]
+    fn now_millis(&self) -> i64 {
+        chrono::Utc::now().timestamp_millis()
+    }
+}
+
+/// A clock stopped at one instant, for signed URLs that can be compared byte for byte.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct FixedClock {
+    micros: i64,
+}
+
+impl FixedClock {
+    pub fn from_millis(millis: i64) -> Self {
+        FixedClock { micros: millis * 1000 }
+    }
+
+    pub fn from_micros(micros: i64) -> Self {
+        FixedClock { micros }
+    }
+}
+
+impl Clock for FixedClock {
+    #[inline]
+    fn now_micros(&self) -> i64 {
+        self.micros
+    }
+}
+
+/// A clock that only moves when told to, e.g. to simulate skew against the server.
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    micros: AtomicI64,
+}
+
+impl ManualClock {
+    pub fn from_millis(millis: i64) -> Self {
+        ManualClock {
+            micros: AtomicI64::new(millis * 1000),
+        }
+    }
+
+    pub fn set_millis(&self, millis: i64) {
+        self.micros.store(millis * 1000, Ordering::Relaxed);
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        self.micros
+            .fetch_add(duration.as_micros() as i64, Ordering::Relaxed);
+    }
+
+    /// Moves the clock back, `advance` with a negative duration.
+    pub fn rewind(&self, duration: Duration) {
+        self.micros
+            .fetch_sub(duration.as_micros() as i64, Ordering::Relaxed);
+    }
+}
+
+impl Clock for ManualClock {
+    #[inline]
+    fn now_micros(&self) -> i64 {
+        self.micros.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::api::client::options::RequestOptions;
+    use crate::api::client::signer::SigningKey;
+    use crate::api::client::url::Url;
+    use crate::api::client::BinanceClient;
+    use crate::types::time::TimeUnit;
+
+    // https://binance-docs.github.io/apidocs/spot/en/#signed-trade-user_data-and-margin-endpoint-security
+    const SECRET_KEY: &str = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j";
+
+    fn docs_order_url() -> Url {
+        let mut url = Url::with_capacity("https://api.binance.com", "/api/v3/order", 256);
+        url.add_params("symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1");
+        url
+    }
+
+    #[test]
+    fn test_signed_url_with_fixed_clock() {
+        let mut builder = BinanceClient::builder("https://api.binance.com".to_owned());
+        builder.signing_key = Some(SigningKey::Hmac(SECRET_KEY.into()));
+        builder.clock = Some(Arc::new(FixedClock::from_millis(1499827319559)));
+        let client = builder.build().unwrap();
+
+        let url = client
+            .signed_url(&docs_order_url(), &RequestOptions::new().recv_window(5000))
+            .unwrap();
+        assert_eq!(
+            url.get_query(),
+            Some(
+                "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1\
+                 &recvWindow=5000&timestamp=1499827319559\
+                 &signature=c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+            ),
+        );
+    }
+
+    #[test]
+    fn test_timestamp_follows_manual_clock_and_server_offset() {
+        let clock = Arc::new(ManualClock::from_millis(1_000_000));
+        let mut builder = BinanceClient::builder("https://api.binance.com".to_owned());
+        builder.clock = Some(clock.clone());
+        let client = builder.build().unwrap();
+
+        assert_eq!(client.timestamp(), 1_000_000);
+
+        // the local clock runs 700ms behind the server
+        client.time_sync.update(client.clock.now_millis(), 1_000_750, client.clock.now_millis() + 100);
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(client.timestamp(), 1_000_800);
+
+        clock.rewind(Duration::from_millis(1_000));
+        assert_eq!(client.timestamp(), 999_800);
+
+        let mut builder = BinanceClient::builder("https://api.binance.com".to_owned());
+        builder.clock = Some(Arc::new(FixedClock::from_micros(1_000_000_123)));
+        builder.time_unit = TimeUnit::Microsecond;
+        assert_eq!(builder.build().unwrap().timestamp(), 1_000_000_123);
+    }
+}
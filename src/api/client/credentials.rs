@@ -0,0 +1,338 @@
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{compiler_fence, Ordering};
+
+use reqwest::header::HeaderValue;
+
+#[derive(Debug)]
+pub enum CredentialsError {
+    /// The key is empty, holds the name of the key.
+    Empty(&'static str),
+    /// The key holds characters other than printable ASCII, e.g. a trailing newline.
+    InvalidCharacter(&'static str),
+    EnvVarNotPresent(String),
+    EnvVarNotUnicode(String),
+    Io(PathBuf, std::io::Error),
+    /// The key file can be accessed by the group or by others, holds its mode.
+    InsecurePermissions(PathBuf, u32),
+}
+
+impl fmt::Display for CredentialsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CredentialsError::Empty(name) => write!(f, "{} is empty", name),
+            CredentialsError::InvalidCharacter(name) => {
+                write!(f, "{} holds characters other than printable ASCII", name)
+            }
+            CredentialsError::EnvVarNotPresent(var) => write!(f, "environment variable {} is not set", var),
+            CredentialsError::EnvVarNotUnicode(var) => {
+                write!(f, "environment variable {} is not valid unicode", var)
+            }
+            CredentialsError::Io(path, err) => write!(f, "cannot read {}: {}", path.display(), err),
+            CredentialsError::InsecurePermissions(path, mode) => write!(
+                f,
+                "{} can be accessed by other users (mode {:o}), restrict it to its owner, e.g. chmod 600",
+                path.display(),
+                mode & 0o777,
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CredentialsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CredentialsError::Io(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// A string wiped from memory when dropped and printed redacted.
+///
+/// Copies the caller made before handing it over, e.g. a `String` literal, are not wiped.
+#[derive(Clone, Default)]
+pub struct SecretString(String);
+
+impl SecretString {
+    pub fn new(secret: impl Into<String>) -> Self {
+        SecretString(secret.into())
+    }
+
+    /// Reads the secret from the environment variable `var`.
+    pub fn from_env(var: &str) -> Result<Self, CredentialsError> {
+        match std::env::var(var) {
+            Ok(secret) => Ok(SecretString(secret)),
+            Err(std::env::VarError::NotPresent) => Err(CredentialsError::EnvVarNotPresent(var.to_owned())),
+            Err(std::env::VarError::NotUnicode(_)) => Err(CredentialsError::EnvVarNotUnicode(var.to_owned())),
+        }
+    }
+
+    /// Reads the secret from a file, without the trailing whitespace.
+    ///
+    /// On Unix the file must not be accessible by the group or by others.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, CredentialsError> {
+        let path = path.as_ref();
+        let io_error = |err| CredentialsError::Io(path.to_owned(), err);
+        // the permissions are checked on the file read, not on whatever the path points to later
+        let mut file = std::fs::File::open(path).map_err(io_error)?;
+        let metadata = file.metadata().map_err(io_error)?;
+        check_permissions(path, &metadata)?;
+
+        // sized up front, a reallocation would leave a copy behind that is never wiped
+        let capacity = usize::try_from(metadata.len()).unwrap_or(0).saturating_add(1);
+        let mut secret = SecretString(String::with_capacity(capacity));
+        file.read_to_string(&mut secret.0).map_err(io_error)?;
+
+        let len = secret.0.trim_end().len();
+        // the cut off bytes stay in the capacity, which is wiped on drop
+        secret.0.truncate(len);
+        Ok(secret)
+    }
+
+    #[inline]
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        SecretString(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        SecretString(secret.to_owned())
+    }
+}
+
+impl From<&String> for SecretString {
+    fn from(secret: &String) -> Self {
+        SecretString(secret.clone())
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        // SAFETY: zeroes are valid UTF-8
+        wipe(unsafe { self.0.as_mut_vec() });
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretString(<redacted>)")
+    }
+}
+
+/// Bytes wiped from memory when dropped and printed redacted, e.g. a decoded private key.
+#[derive(Clone, Default)]
+pub struct SecretBytes(Vec<u8>);
+
+impl SecretBytes {
+    pub fn new(secret: impl Into<Vec<u8>>) -> Self {
+        SecretBytes(secret.into())
+    }
+
+    #[inline]
+    pub fn expose_secret(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for SecretBytes {
+    fn from(secret: Vec<u8>) -> Self {
+        SecretBytes(secret)
+    }
+}
+
+impl Drop for SecretBytes {
+    fn drop(&mut self) {
+        wipe(&mut self.0);
+    }
+}
+
+impl fmt::Debug for SecretBytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretBytes(<redacted>)")
+    }
+}
+
+/// Zeroes the whole allocation of `buf`, its spare capacity included.
+fn wipe(buf: &mut Vec<u8>) {
+    let ptr = buf.as_mut_ptr();
+    for i in 0..buf.capacity() {
+        // SAFETY: only the allocation of the vector is written
+        unsafe { std::ptr::write_volatile(ptr.add(i), 0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+#[cfg(unix)]
+fn check_permissions(path: &Path, metadata: &std::fs::Metadata) -> Result<(), CredentialsError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = metadata.permissions().mode();
+    if mode & 0o077 != 0 {
+        return Err(CredentialsError::InsecurePermissions(path.to_owned(), mode));
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn check_permissions(_path: &Path, _metadata: &std::fs::Metadata) -> Result<(), CredentialsError> {
+    Ok(())
+}
+
+/// Binance keys are printable ASCII, anything else is a copy and paste error.
+fn validate(secret: &SecretString, name: &'static str) -> Result<(), CredentialsError> {
+    if secret.is_empty() {
+        return Err(CredentialsError::Empty(name));
+    }
+    if !secret.expose_secret().bytes().all(|b| b.is_ascii_graphic()) {
+        return Err(CredentialsError::InvalidCharacter(name));
+    }
+    Ok(())
+}
+
+/// API key sent in the `X-MBX-APIKEY` header.
+#[derive(Clone)]
+pub struct ApiKey(SecretString);
+
+impl ApiKey {
+    pub fn new(api_key: impl Into<SecretString>) -> Result<Self, CredentialsError> {
+        let api_key = api_key.into();
+        validate(&api_key, "api key")?;
+        Ok(ApiKey(api_key))
+    }
+
+    pub fn from_env(var: &str) -> Result<Self, CredentialsError> {
+        ApiKey::new(SecretString::from_env(var)?)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, CredentialsError> {
+        ApiKey::new(SecretString::from_file(path)?)
+    }
+
+    #[inline]
+    pub fn expose_secret(&self) -> &str {
+        self.0.expose_secret()
+    }
+
+    /// Marked sensitive, so it is redacted when the headers are printed.
+    pub(crate) fn header_value(&self) -> Result<HeaderValue, CredentialsError> {
+        let mut value = HeaderValue::from_str(self.expose_secret())
+            .map_err(|_| CredentialsError::InvalidCharacter("api key"))?;
+        value.set_sensitive(true);
+        Ok(value)
+    }
+}
+
+impl From<ApiKey> for SecretString {
+    fn from(api_key: ApiKey) -> Self {
+        api_key.0
+    }
+}
+
+impl fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ApiKey(<redacted>)")
+    }
+}
+
+/// HMAC secret key.
+#[derive(Clone)]
+pub struct SecretKey(SecretString);
+
+impl SecretKey {
+    pub fn new(secret_key: impl Into<SecretString>) -> Result<Self, CredentialsError> {
+        let secret_key = secret_key.into();
+        validate(&secret_key, "secret key")?;
+        Ok(SecretKey(secret_key))
+    }
+
+    pub fn from_env(var: &str) -> Result<Self, CredentialsError> {
+        SecretKey::new(SecretString::from_env(var)?)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, CredentialsError> {
+        SecretKey::new(SecretString::from_file(path)?)
+    }
+
+    #[inline]
+    pub fn expose_secret(&self) -> &str {
+        self.0.expose_secret()
+    }
+}
+
+impl From<SecretKey> for SecretString {
+    fn from(secret_key: SecretKey) -> Self {
+        secret_key.0
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretKey(<redacted>)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const API_KEY: &str = "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A";
+
+    #[test]
+    fn test_keys_are_validated_and_redacted() {
+        let api_key = ApiKey::new(API_KEY).unwrap();
+        assert_eq!(api_key.expose_secret(), API_KEY);
+        assert_eq!(format!("{:?}", api_key), "ApiKey(<redacted>)");
+        assert!(api_key.header_value().unwrap().is_sensitive());
+
+        assert!(matches!(ApiKey::new(""), Err(CredentialsError::Empty("api key"))));
+        assert!(matches!(
+            SecretKey::new(format!("{}\n", API_KEY)),
+            Err(CredentialsError::InvalidCharacter("secret key")),
+        ));
+        assert!(!format!("{:?}", SecretString::new(API_KEY)).contains(API_KEY));
+        assert_eq!(format!("{:?}", SecretBytes::new(API_KEY)), "SecretBytes(<redacted>)");
+    }
+
+    #[test]
+    fn test_load_keys_from_env() {
+        std::env::set_var("BINANCEX_TEST_API_KEY", API_KEY);
+        assert_eq!(ApiKey::from_env("BINANCEX_TEST_API_KEY").unwrap().expose_secret(), API_KEY);
+        assert!(matches!(
+            SecretKey::from_env("BINANCEX_TEST_MISSING_SECRET_KEY"),
+            Err(CredentialsError::EnvVarNotPresent(_)),
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_load_key_from_file_checks_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join(format!("binancex-test-secret-{}", std::process::id()));
+        std::fs::write(&path, format!("{}\n", API_KEY)).unwrap();
+
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        let err = SecretKey::from_file(&path).unwrap_err();
+        assert!(matches!(err, CredentialsError::InsecurePermissions(_, mode) if mode & 0o777 == 0o644));
+
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).unwrap();
+        let secret_key = SecretKey::from_file(&path).unwrap();
+        assert_eq!(secret_key.expose_secret(), API_KEY);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
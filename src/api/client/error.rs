@@ -1,19 +1,114 @@
-use super::response::Response;
+use std::fmt;
 
-pub type HeaderParseIntError = std::num::ParseIntError;
+use super::credentials::CredentialsError;
+use super::json::JsonError;
+use super::response::{BinanceHttpHeader, Response};
+use super::signer::SignerError;
+use super::transport::TransportError;
+use crate::api::accounts::AccountError;
+use crate::schemes::error::ApiError;
+use crate::types::error::{ErrorCategory, ErrorCode};
 
 #[derive(Debug)]
 pub enum BinanceError<E> {
+    /// The profiles of an `AccountRegistry` are invalid.
+    Account(AccountError),
     Binance(Response<E>),
-    HeaderParseInt(HeaderParseIntError),
+    Credentials(CredentialsError),
     HttpClient(reqwest::Error),
     ParseQueryString(serde_qs::Error),
+    /// The request was not sent because it would exceed the client side weight budget.
+    /// Holds the time until enough weight is available.
+    RateLimited(std::time::Duration),
+    Runtime(tokio::runtime::TryCurrentError),
+    Signer(SignerError),
+    Transport(TransportError),
+    ParseJson(JsonError),
+}
+
+impl<E> BinanceError<E> {
+    /// Rate limit headers of a Binance error response.
+    pub fn headers(&self) -> Option<&BinanceHttpHeader> {
+        match self {
+            BinanceError::Binance(resp) => Some(&resp.headers),
+            _ => None,
+        }
+    }
+}
+
+impl<E: ApiError> BinanceError<E> {
+    /// Error code of a Binance error response.
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        match self {
+            BinanceError::Binance(resp) => Some(resp.payload.error_code()),
+            _ => None,
+        }
+    }
+
+    pub fn category(&self) -> Option<ErrorCategory> {
+        match self {
+            BinanceError::Binance(resp) => Some(resp.payload.category()),
+            BinanceError::RateLimited(_) => Some(ErrorCategory::RateLimit),
+            _ => None,
+        }
+    }
+
+    /// Whether the same request may succeed when sent again later.
+    ///
+    /// Errors with an unknown execution status are not, even with a 5xx status: the request,
+    /// e.g. an order, may have been executed, check its status before sending it again.
+    /// This includes transport errors other than a failed connect, like timeouts.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            BinanceError::Binance(resp) => {
+                let code = resp.payload.error_code();
+                !code.is_execution_status_unknown()
+                    && (code.is_retryable() || resp.status.is_server_error() || resp.status.as_u16() == 429)
+            }
+            BinanceError::RateLimited(_) => true,
+            BinanceError::Transport(err) => err.is_connect(),
+            _ => false,
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for BinanceError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BinanceError::Account(err) => write!(f, "{}", err),
+            BinanceError::Binance(resp) => write!(f, "binance error (HTTP {}): {}", resp.status, resp.payload),
+            BinanceError::Credentials(err) => write!(f, "invalid credentials: {}", err),
+            BinanceError::HttpClient(err) => write!(f, "http client error: {}", err),
+            BinanceError::ParseQueryString(err) => write!(f, "invalid query: {}", err),
+            BinanceError::RateLimited(wait) => write!(f, "rate limited, retry in {:?}", wait),
+            BinanceError::Runtime(err) => write!(f, "{}", err),
+            BinanceError::Signer(err) => write!(f, "signer error: {}", err),
+            BinanceError::Transport(err) => write!(f, "{}", err),
+            BinanceError::ParseJson(err) => write!(f, "invalid response body: {}", err),
+        }
+    }
+}
 
-    #[cfg(all(feature = "serde_json", not(feature = "simd_json")))]
-    ParseJson(serde_json::Error),
+impl<E: fmt::Debug + fmt::Display> std::error::Error for BinanceError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BinanceError::Account(err) => Some(err),
+            BinanceError::Binance(_) | BinanceError::RateLimited(_) => None,
+            BinanceError::Credentials(err) => Some(err),
+            BinanceError::HttpClient(err) => Some(err),
+            BinanceError::ParseQueryString(err) => Some(err),
+            BinanceError::Runtime(err) => Some(err),
+            BinanceError::Signer(err) => Some(err),
+            BinanceError::Transport(err) => Some(err),
+            BinanceError::ParseJson(err) => Some(err),
+        }
+    }
+}
 
-    #[cfg(all(feature = "simd_json", not(feature = "serde_json")))]
-    ParseJson(simd_json::Error),
+impl<E> From<AccountError> for BinanceError<E> {
+    fn from(err: AccountError) -> Self {
+        BinanceError::Account(err)
+    }
 }
 
 impl<E> From<Response<E>> for BinanceError<E> {
@@ -22,9 +117,9 @@ impl<E> From<Response<E>> for BinanceError<E> {
     }
 }
 
-impl<E> From<HeaderParseIntError> for BinanceError<E> {
-    fn from(err: HeaderParseIntError) -> Self {
-        BinanceError::HeaderParseInt(err)
+impl<E> From<CredentialsError> for BinanceError<E> {
+    fn from(err: CredentialsError) -> Self {
+        BinanceError::Credentials(err)
     }
 }
 
@@ -40,16 +135,26 @@ impl<E> From<serde_qs::Error> for BinanceError<E> {
     }
 }
 
-#[cfg(all(feature = "serde_json", not(feature = "simd_json")))]
-impl<E> From<serde_json::Error> for BinanceError<E> {
-    fn from(err: serde_json::Error) -> Self {
-        BinanceError::ParseJson(err)
+impl<E> From<SignerError> for BinanceError<E> {
+    fn from(err: SignerError) -> Self {
+        BinanceError::Signer(err)
+    }
+}
+
+impl<E> From<TransportError> for BinanceError<E> {
+    fn from(err: TransportError) -> Self {
+        BinanceError::Transport(err)
+    }
+}
+
+impl<E> From<tokio::runtime::TryCurrentError> for BinanceError<E> {
+    fn from(err: tokio::runtime::TryCurrentError) -> Self {
+        BinanceError::Runtime(err)
     }
 }
 
-#[cfg(all(feature = "simd_json", not(feature = "serde_json")))]
-impl<E> From<simd_json::Error> for BinanceError<E> {
-    fn from(err: simd_json::Error) -> Self {
+impl<E> From<JsonError> for BinanceError<E> {
+    fn from(err: JsonError) -> Self {
         BinanceError::ParseJson(err)
     }
 }
@@ -0,0 +1,204 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::BinanceClient;
+
+/// How the host of the next request is picked among the healthy ones.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum HostSelection {
+    /// The first healthy host in the configured order.
+    #[default]
+    Priority,
+    /// The healthy host with the lowest `ping` latency, hosts never pinged come last.
+    Fastest,
+}
+
+/// Health of a host, as seen by the client.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HostStatus {
+    pub host: Arc<str>,
+    pub healthy: bool,
+    /// Smoothed `ping` round-trip time, `None` until the first health check.
+    pub latency: Option<Duration>,
+}
+
+#[derive(Debug)]
+struct HostState {
+    host: Arc<str>,
+    healthy: AtomicBool,
+    /// EWMA of the `ping` latency in microseconds, 0 if unknown.
+    latency_us: AtomicU64,
+}
+
+/// Ordered list of alternate API hosts, e.g. `api1`..`api4` and `api-gcp` of `api.binance.com`.
+///
+/// Hosts are marked unhealthy on connect errors, timeouts and 5xx responses
+/// and healthy again on any other response or a successful health check.
+/// If no host is healthy, the selection falls back to all of them.
+#[derive(Debug)]
+pub struct HostPool {
+    hosts: Vec<HostState>,
+    selection: HostSelection,
+}
+
+impl HostPool {
+    /// # Panics
+    ///
+    /// If `hosts` is empty.
+    pub fn new<I, S>(hosts: I, selection: HostSelection) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let hosts: Vec<_> = hosts
+            .into_iter()
+            .map(|host| HostState {
+                host: Arc::from(host.into()),
+                healthy: AtomicBool::new(true),
+                latency_us: AtomicU64::new(0),
+            })
+            .collect();
+        assert!(!hosts.is_empty(), "host pool needs at least one host");
+
+        HostPool { hosts, selection }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.hosts.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.hosts.is_empty()
+    }
+
+    #[inline]
+    pub fn selection(&self) -> HostSelection {
+        self.selection
+    }
+
+    pub fn status(&self) -> Vec<HostStatus> {
+        self.hosts
+            .iter()
+            .map(|state| HostStatus {
+                host: state.host.clone(),
+                healthy: state.healthy.load(Ordering::Relaxed),
+                latency: match state.latency_us.load(Ordering::Relaxed) {
+                    0 => None,
+                    us => Some(Duration::from_micros(us)),
+                },
+            })
+            .collect()
+    }
+
+    #[inline]
+    pub(crate) fn host(&self, idx: usize) -> &Arc<str> {
+        &self.hosts[idx].host
+    }
+
+    /// Index of the host for the next attempt, `avoid` is the host that failed the previous one.
+    pub(crate) fn select(&self, avoid: Option<usize>) -> usize {
+        let candidates = |healthy_only: bool| {
+            self.hosts.iter().enumerate().filter(move |(idx, state)| {
+                Some(*idx) != avoid && (!healthy_only || state.healthy.load(Ordering::Relaxed))
+            })
+        };
+        let pick = |healthy_only: bool| match self.selection {
+            HostSelection::Priority => candidates(healthy_only).map(|(idx, _)| idx).next(),
+            HostSelection::Fastest => candidates(healthy_only)
+                .min_by_key(|(idx, state)| {
+                    let latency = state.latency_us.load(Ordering::Relaxed);
+                    (if latency == 0 { u64::MAX } else { latency }, *idx)
+                })
+                .map(|(idx, _)| idx),
+        };
+
+        pick(true).or_else(|| pick(false)).or(avoid).unwrap_or(0)
+    }
+
+    #[inline]
+    pub(crate) fn mark_healthy(&self, idx: usize) {
+        self.hosts[idx].healthy.store(true, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn mark_unhealthy(&self, idx: usize) {
+        self.hosts[idx].healthy.store(false, Ordering::Relaxed);
+    }
+
+    /// Result of a health check, `None` if the host did not answer `ping` successfully.
+    pub(crate) fn record_ping(&self, idx: usize, latency: Option<Duration>) {
+        let state = &self.hosts[idx];
+        let latency = match latency {
+            Some(latency) => latency,
+            None => return self.mark_unhealthy(idx),
+        };
+
+        let sample = (latency.as_micros() as u64).max(1);
+        let prev = state.latency_us.load(Ordering::Relaxed);
+        let smoothed = if prev == 0 { sample } else { (prev * 3 + sample) / 4 };
+        state.latency_us.store(smoothed, Ordering::Relaxed);
+        state.healthy.store(true, Ordering::Relaxed);
+    }
+}
+
+pub(crate) async fn run_host_health_check(client: BinanceClient, path: &'static str, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        client.check_hosts(path).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOSTS: [&str; 3] = [
+        "https://api.binance.com",
+        "https://api1.binance.com",
+        "https://api-gcp.binance.com",
+    ];
+
+    #[test]
+    fn test_priority_selection_skips_unhealthy_hosts() {
+        let pool = HostPool::new(HOSTS, HostSelection::Priority);
+        assert_eq!(pool.select(None), 0);
+        assert_eq!(pool.select(Some(0)), 1);
+
+        pool.mark_unhealthy(0);
+        assert_eq!(pool.select(None), 1);
+
+        pool.mark_unhealthy(1);
+        pool.mark_unhealthy(2);
+        assert_eq!(pool.select(None), 0);
+        assert_eq!(pool.select(Some(0)), 1);
+
+        pool.mark_healthy(2);
+        assert_eq!(pool.select(None), 2);
+    }
+
+    #[test]
+    fn test_fastest_selection_uses_ping_latency() {
+        let pool = HostPool::new(HOSTS, HostSelection::Fastest);
+        assert_eq!(pool.select(None), 0);
+
+        pool.record_ping(0, Some(Duration::from_millis(40)));
+        pool.record_ping(1, Some(Duration::from_millis(10)));
+        pool.record_ping(2, None);
+        assert_eq!(pool.select(None), 1);
+        assert_eq!(pool.select(Some(1)), 0);
+
+        pool.record_ping(1, Some(Duration::from_millis(90)));
+        assert_eq!(pool.status()[1].latency, Some(Duration::from_micros(30_000)));
+        assert_eq!(pool.select(None), 1);
+        pool.record_ping(1, Some(Duration::from_millis(90)));
+        assert_eq!(pool.select(None), 0);
+
+        assert!(!pool.status()[2].healthy);
+    }
+}
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use http::Method;
+use reqwest::{header::HeaderMap, StatusCode};
+
+use super::transport::TransportError;
+
+/// A request as it is about to be sent.
+#[derive(Debug)]
+pub struct RequestParts<'a> {
+    pub method: &'a Method,
+    /// The final URL, including the query, timestamp and signature of signed requests
+    /// unless they are sent in the body.
+    pub url: &'a str,
+    /// Form-encoded body of requests that send their params in the body.
+    pub body: Option<&'a [u8]>,
+    pub path: &'a str,
+    /// Number of the attempt, starting from 1.
+    pub attempt: u32,
+    /// Request weight reserved for the attempt.
+    pub weight: u32,
+    pub headers: &'a mut HeaderMap,
+}
+
+/// Metadata of a received response, before its body is parsed.
+#[derive(Debug)]
+pub struct ResponseMeta<'a> {
+    pub status: StatusCode,
+    pub headers: &'a HeaderMap,
+    pub body_len: usize,
+    /// Time from sending the request to receiving the whole body.
+    pub elapsed: Duration,
+}
+
+/// Hooks run around every attempt of every request, in the order they were registered.
+pub trait Interceptor: Send + Sync {
+    /// Called right before sending, headers may be added or changed.
+    fn before_request(&self, _request: &mut RequestParts) {}
+
+    /// Called with the response, or with the error if no response was received.
+    /// Error responses of the API (4xx/5xx with a Binance error body) come as a `ResponseMeta`.
+    fn after_response(&self, _request: &RequestParts, _outcome: Result<&ResponseMeta, &TransportError>) {}
+}
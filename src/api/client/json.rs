@@ -1,40 +1,640 @@
-use serde::{
-    de::Deserialize,
-    // ser::Serialize,
-};
-
-// #[cfg(all(feature = "serde_json", not(feature = "simd_json")))]
-// #[inline(always)]
-// pub fn to_vec<S: Serialize + ?Sized>(json: &S) -> Result<Vec<u8>, serde_json::Error> {
-//     serde_json::to_vec(json)
-// }
-//
-// #[cfg(all(feature = "simd_json", not(feature = "serde_json")))]
-// #[inline(always)]
-// pub fn to_vec<S: Serialize + ?Sized>(json: &S) -> Result<Vec<u8>, simd_json::Error> {
-//     simd_json::to_vec(json)
-// }
-
-#[cfg(all(feature = "serde_json", not(feature = "simd_json")))]
-#[inline(always)]
-pub async fn from_slice<D>(s: &[u8]) -> Result<D, serde_json::Error>
+use std::fmt;
+use std::sync::Arc;
+#[cfg(feature = "simd_json")]
+use std::sync::{Mutex, OnceLock};
+
+use serde::de::{Deserialize, Deserializer};
+
+use crate::types::time::{deserialize_in, TimeUnit};
+
+#[cfg(not(any(feature = "serde_json", feature = "simd_json", feature = "sonic_rs")))]
+compile_error!("at least one of the `serde_json`, `simd_json` and `sonic_rs` features must be enabled");
+
+const EXCERPT_CONTEXT: usize = 32;
+
+/// Buffers a simd-json decoder keeps around, about the number of requests in flight.
+#[cfg(feature = "simd_json")]
+const SIMD_JSON_POOL_SIZE: usize = 8;
+/// Larger buffers are dropped after use, not to hold a few MB per exchangeInfo forever.
+#[cfg(feature = "simd_json")]
+const SIMD_JSON_POOL_MAX_CAPACITY: usize = 4 * 1024 * 1024;
+
+/// Pool of [`JsonBackend::SimdJson`] used on its own, clients keep one of their own.
+#[cfg(feature = "simd_json")]
+static SIMD_JSON_SHARED: OnceLock<SimdJsonDecoder> = OnceLock::new();
+
+/// Decodes response bodies.
+///
+/// Implemented by every backend compiled in and by [`JsonBackend`], which picks one at runtime.
+/// Clients are built with a [`JsonBackend`] or a [`DynJsonDecoder`].
+pub trait JsonDecoder {
+    /// Decodes the body with the timestamps within read in `time_unit`.
+    fn decode_in<D>(&self, body: &[u8], time_unit: TimeUnit) -> Result<D, JsonError>
+    where
+        D: for<'de> Deserialize<'de>;
+
+    /// Decodes the body with the timestamps within read in milliseconds.
+    #[inline]
+    fn decode<D>(&self, body: &[u8]) -> Result<D, JsonError>
+    where
+        D: for<'de> Deserialize<'de>,
+    {
+        self.decode_in(body, TimeUnit::Millisecond)
+    }
+}
+
+/// Decodes response bodies for a client, see `BinanceBuilder::json_decoder`.
+///
+/// The object safe counterpart of [`JsonDecoder`] for decoders other than the [`JsonBackend`]s:
+/// `deserialize` hands a type-erased deserializer over the body to `visit`, which decodes the
+/// response type from it. Errors of the decoder itself can be made with `serde::de::Error::custom`.
+pub trait DynJsonDecoder: Send + Sync {
+    /// Name shown in [`JsonError`]s.
+    fn name(&self) -> &'static str;
+
+    fn deserialize(
+        &self,
+        body: &[u8],
+        visit: &mut dyn FnMut(&mut dyn erased_serde::Deserializer) -> Result<(), erased_serde::Error>,
+    ) -> Result<(), erased_serde::Error>;
+}
+
+/// JSON backends compiled in with the `serde_json`, `simd_json` and `sonic_rs` features.
+///
+/// The default is the fastest one available: sonic-rs, then simd-json, then serde_json.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum JsonBackend {
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(not(any(feature = "simd_json", feature = "sonic_rs")), default)]
+    SerdeJson,
+    #[cfg(feature = "simd_json")]
+    #[cfg_attr(not(feature = "sonic_rs"), default)]
+    SimdJson,
+    #[cfg(feature = "sonic_rs")]
+    #[default]
+    SonicRs,
+}
+
+impl JsonBackend {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "serde_json")]
+            JsonBackend::SerdeJson => "serde_json",
+            #[cfg(feature = "simd_json")]
+            JsonBackend::SimdJson => "simd_json",
+            #[cfg(feature = "sonic_rs")]
+            JsonBackend::SonicRs => "sonic_rs",
+        }
+    }
+}
+
+impl fmt::Display for JsonBackend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// [`JsonBackend::SimdJson`] decodes with buffers shared by the whole process.
+impl JsonDecoder for JsonBackend {
+    #[inline]
+    fn decode_in<D>(&self, body: &[u8], time_unit: TimeUnit) -> Result<D, JsonError>
+    where
+        D: for<'de> Deserialize<'de>,
+    {
+        match self {
+            #[cfg(feature = "serde_json")]
+            JsonBackend::SerdeJson => SerdeJsonDecoder.decode_in(body, time_unit),
+            #[cfg(feature = "simd_json")]
+            JsonBackend::SimdJson => SIMD_JSON_SHARED.get_or_init(SimdJsonDecoder::default).decode_in(body, time_unit),
+            #[cfg(feature = "sonic_rs")]
+            JsonBackend::SonicRs => SonicRsDecoder.decode_in(body, time_unit),
+        }
+    }
+}
+
+/// The backend of a client with the buffers its decoder reuses between responses,
+/// or the custom decoder it was built with.
+#[derive(Clone, Default)]
+pub(crate) struct ClientJsonDecoder {
+    backend: JsonBackend,
+    #[cfg(feature = "simd_json")]
+    simd_json: SimdJsonDecoder,
+    custom: Option<Arc<dyn DynJsonDecoder>>,
+}
+
+impl ClientJsonDecoder {
+    pub(crate) fn new(backend: JsonBackend, custom: Option<Arc<dyn DynJsonDecoder>>) -> Self {
+        ClientJsonDecoder {
+            backend,
+            #[cfg(feature = "simd_json")]
+            simd_json: SimdJsonDecoder::default(),
+            custom,
+        }
+    }
+}
+
+impl fmt::Debug for ClientJsonDecoder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.custom.as_ref() {
+            Some(custom) => f.debug_tuple("ClientJsonDecoder").field(&custom.name()).finish(),
+            None => f.debug_tuple("ClientJsonDecoder").field(&self.backend).finish(),
+        }
+    }
+}
+
+impl JsonDecoder for ClientJsonDecoder {
+    #[inline]
+    fn decode_in<D>(&self, body: &[u8], time_unit: TimeUnit) -> Result<D, JsonError>
+    where
+        D: for<'de> Deserialize<'de>,
+    {
+        if let Some(custom) = self.custom.as_deref() {
+            return decode_dyn(custom, body, time_unit);
+        }
+        match self.backend {
+            #[cfg(feature = "serde_json")]
+            JsonBackend::SerdeJson => SerdeJsonDecoder.decode_in(body, time_unit),
+            #[cfg(feature = "simd_json")]
+            JsonBackend::SimdJson => self.simd_json.decode_in(body, time_unit),
+            #[cfg(feature = "sonic_rs")]
+            JsonBackend::SonicRs => SonicRsDecoder.decode_in(body, time_unit),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SerdeJsonDecoder;
+
+#[cfg(feature = "serde_json")]
+impl JsonDecoder for SerdeJsonDecoder {
+    #[inline]
+    fn decode_in<D>(&self, body: &[u8], time_unit: TimeUnit) -> Result<D, JsonError>
+    where
+        D: for<'de> Deserialize<'de>,
+    {
+        let mut deserializer = serde_json::Deserializer::from_slice(body);
+        let result = deserialize_in(&mut deserializer, time_unit);
+        result.and_then(|value| deserializer.end().map(|()| value)).map_err(|err| {
+            let path = path_of::<_, D>(&mut serde_json::Deserializer::from_slice(body));
+            let offset = offset_of(body, err.line(), err.column());
+            JsonError::new(JsonBackend::SerdeJson, &err, path, offset, body)
+        })
+    }
+}
+
+/// simd-json decoder reusing its buffers between calls.
+///
+/// simd-json parses in place, so the body is copied into a pooled buffer first,
+/// the body itself stays untouched. Clones share the pool.
+#[cfg(feature = "simd_json")]
+#[derive(Clone, Default)]
+pub struct SimdJsonDecoder {
+    pool: Arc<Mutex<Vec<SimdJsonBuffers>>>,
+}
+
+#[cfg(feature = "simd_json")]
+struct SimdJsonBuffers {
+    input: Vec<u8>,
+    parser: simd_json::Buffers,
+}
+
+#[cfg(feature = "simd_json")]
+impl SimdJsonDecoder {
+    /// Number of idle buffers in the pool.
+    pub fn pooled(&self) -> usize {
+        self.pool.lock().unwrap().len()
+    }
+
+    fn take(&self, len: usize) -> SimdJsonBuffers {
+        let buffers = self.pool.lock().unwrap().pop();
+        buffers.unwrap_or_else(|| SimdJsonBuffers {
+            input: Vec::with_capacity(len),
+            parser: simd_json::Buffers::new(len),
+        })
+    }
+
+    fn put(&self, buffers: SimdJsonBuffers) {
+        if buffers.input.capacity() > SIMD_JSON_POOL_MAX_CAPACITY {
+            return;
+        }
+        let mut pool = self.pool.lock().unwrap();
+        if pool.len() < SIMD_JSON_POOL_SIZE {
+            pool.push(buffers);
+        }
+    }
+}
+
+#[cfg(feature = "simd_json")]
+impl fmt::Debug for SimdJsonDecoder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SimdJsonDecoder")
+            .field("pooled", &self.pooled())
+            .finish()
+    }
+}
+
+#[cfg(feature = "simd_json")]
+impl JsonDecoder for SimdJsonDecoder {
+    fn decode_in<D>(&self, body: &[u8], time_unit: TimeUnit) -> Result<D, JsonError>
+    where
+        D: for<'de> Deserialize<'de>,
+    {
+        let mut buffers = self.take(body.len());
+        buffers.input.clear();
+        buffers.input.extend_from_slice(body);
+        let result = simd_json::Deserializer::from_slice_with_buffers(&mut buffers.input, &mut buffers.parser)
+            .and_then(|mut deserializer| deserialize_in(&mut deserializer, time_unit));
+        self.put(buffers);
+
+        result.map_err(|err| {
+            let mut buf = body.to_vec();
+            let path = simd_json::Deserializer::from_slice(&mut buf)
+                .ok()
+                .and_then(|mut deserializer| path_of::<_, D>(&mut deserializer));
+            // errors raised while deserializing the tape carry no position, the path tells where
+            let offset = Some(err.index())
+                .filter(|&index| index > 0)
+                .or_else(|| offset_of_path(body, path.as_deref()?));
+            JsonError::new(JsonBackend::SimdJson, &err, path, offset, body)
+        })
+    }
+}
+
+#[cfg(feature = "sonic_rs")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SonicRsDecoder;
+
+#[cfg(feature = "sonic_rs")]
+impl JsonDecoder for SonicRsDecoder {
+    #[inline]
+    fn decode_in<D>(&self, body: &[u8], time_unit: TimeUnit) -> Result<D, JsonError>
+    where
+        D: for<'de> Deserialize<'de>,
+    {
+        let mut deserializer = sonic_rs::Deserializer::from_slice(body);
+        let result = deserialize_in(&mut deserializer, time_unit);
+        result.and_then(|value| deserializer.end().map(|()| value)).map_err(|err| {
+            let path = path_of::<_, D>(&mut sonic_rs::Deserializer::from_slice(body));
+            let offset = offset_of(body, err.line(), err.column());
+            JsonError::new(JsonBackend::SonicRs, &err, path, offset, body)
+        })
+    }
+}
+
+fn decode_dyn<D>(decoder: &dyn DynJsonDecoder, body: &[u8], time_unit: TimeUnit) -> Result<D, JsonError>
 where
     D: for<'de> Deserialize<'de>,
 {
-    serde_json::from_slice(&s)
+    let mut value = None;
+    let result = decoder.deserialize(body, &mut |deserializer| {
+        value = Some(deserialize_in::<D, _>(deserializer, time_unit)?);
+        Ok(())
+    });
+    let err = match (result, value) {
+        (Ok(()), Some(value)) => return Ok(value),
+        (Ok(()), None) => <erased_serde::Error as serde::de::Error>::custom("the body was not decoded"),
+        (Err(err), _) => err,
+    };
+
+    let mut path = None;
+    let _ = decoder.deserialize(body, &mut |deserializer| {
+        path = path_of::<_, D>(deserializer);
+        Ok(())
+    });
+    let offset = path.as_deref().and_then(|path| offset_of_path(body, path));
+    Err(JsonError::with_decoder(decoder.name(), None, &err, path, offset, body))
 }
 
-#[cfg(all(feature = "simd_json", not(feature = "serde_json")))]
-#[inline]
-pub async fn from_slice<D>(s: &[u8]) -> Result<D, simd_json::Error>
+/// Path of the value that failed to decode, found by decoding the body again.
+///
+/// Only done after a failure, tracking the path slows down the decoding.
+fn path_of<'de, De, D>(deserializer: De) -> Option<String>
 where
-    D: for<'de> Deserialize<'de>,
+    De: Deserializer<'de>,
+    D: Deserialize<'de>,
 {
-    let len = s.len();
-    unsafe {
-        let s_ptr = s.as_ptr() as *mut u8;
-        let mut json_slice = std::slice::from_raw_parts_mut(s_ptr, len);
+    match serde_path_to_error::deserialize::<_, D>(deserializer) {
+        Err(err) if err.path().iter().next().is_some() => Some(err.path().to_string()),
+        _ => None,
+    }
+}
+
+/// Byte offset of the value at `path`, e.g. `symbols[3].filters[0].minPrice`.
+///
+/// For decoders that report no position, the body is scanned without being decoded.
+fn offset_of_path(body: &[u8], path: &str) -> Option<usize> {
+    let mut pos = skip_whitespace(body, 0);
+    let mut rest = path;
+    while !rest.is_empty() {
+        if let Some(index) = rest.strip_prefix('[') {
+            let (index, tail) = index.split_once(']')?;
+            pos = find_element(body, pos, index.parse().ok()?)?;
+            rest = tail;
+        } else {
+            let key = rest.strip_prefix('.').unwrap_or(rest);
+            let end = key.find(['.', '[']).unwrap_or(key.len());
+            pos = find_member(body, pos, &key[..end])?;
+            rest = &key[end..];
+        }
+    }
+    Some(pos)
+}
+
+fn find_element(body: &[u8], mut pos: usize, index: usize) -> Option<usize> {
+    if body.get(pos) != Some(&b'[') {
+        return None;
+    }
+    pos += 1;
+    for i in 0.. {
+        pos = skip_whitespace(body, pos);
+        if body.get(pos) == Some(&b']') {
+            return None;
+        }
+        if i == index {
+            return Some(pos);
+        }
+        pos = skip_whitespace(body, skip_value(body, pos)?);
+        if body.get(pos) == Some(&b',') {
+            pos += 1;
+        }
+    }
+    None
+}
+
+fn find_member(body: &[u8], mut pos: usize, key: &str) -> Option<usize> {
+    if body.get(pos) != Some(&b'{') {
+        return None;
+    }
+    pos += 1;
+    loop {
+        pos = skip_whitespace(body, pos);
+        if body.get(pos) != Some(&b'"') {
+            return None;
+        }
+        let key_end = skip_string(body, pos)?;
+        let name = &body[pos + 1..key_end - 1];
+        pos = skip_whitespace(body, key_end);
+        if body.get(pos) != Some(&b':') {
+            return None;
+        }
+        pos = skip_whitespace(body, pos + 1);
+        if name == key.as_bytes() {
+            return Some(pos);
+        }
+        pos = skip_whitespace(body, skip_value(body, pos)?);
+        if body.get(pos) == Some(&b',') {
+            pos += 1;
+        }
+    }
+}
+
+fn skip_whitespace(body: &[u8], pos: usize) -> usize {
+    pos + body[pos.min(body.len())..]
+        .iter()
+        .take_while(|b| b.is_ascii_whitespace())
+        .count()
+}
+
+/// Position after the string starting at `pos`.
+fn skip_string(body: &[u8], pos: usize) -> Option<usize> {
+    let mut i = pos + 1;
+    while i < body.len() {
+        match body[i] {
+            b'\\' => i += 2,
+            b'"' => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Position after the value starting at `pos`.
+fn skip_value(body: &[u8], pos: usize) -> Option<usize> {
+    match body.get(pos)? {
+        b'"' => skip_string(body, pos),
+        b'[' | b'{' => {
+            let mut depth = 0;
+            let mut i = pos;
+            while i < body.len() {
+                match body[i] {
+                    b'"' => {
+                        i = skip_string(body, i)?;
+                        continue;
+                    }
+                    b'[' | b'{' => depth += 1,
+                    b']' | b'}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(i + 1);
+                        }
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+            None
+        }
+        _ => Some(
+            pos + body[pos..]
+                .iter()
+                .take_while(|&&b| !matches!(b, b',' | b']' | b'}') && !b.is_ascii_whitespace())
+                .count(),
+        ),
+    }
+}
+
+/// Byte offset of a 1-based line and column.
+#[cfg(any(feature = "serde_json", feature = "sonic_rs"))]
+fn offset_of(body: &[u8], line: usize, column: usize) -> Option<usize> {
+    if line == 0 {
+        return None;
+    }
+    let line_start = match line {
+        1 => 0,
+        _ => {
+            body.iter()
+                .enumerate()
+                .filter(|(_, &b)| b == b'\n')
+                .nth(line - 2)?
+                .0
+                + 1
+        }
+    };
+    Some((line_start + column.saturating_sub(1)).min(body.len()))
+}
+
+/// A response body that could not be decoded, whatever the JSON backend.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JsonError {
+    decoder: &'static str,
+    backend: Option<JsonBackend>,
+    message: String,
+    path: Option<String>,
+    offset: Option<usize>,
+    excerpt: String,
+}
+
+impl JsonError {
+    fn new(
+        backend: JsonBackend,
+        err: &dyn fmt::Display,
+        path: Option<String>,
+        offset: Option<usize>,
+        body: &[u8],
+    ) -> Self {
+        Self::with_decoder(backend.as_str(), Some(backend), err, path, offset, body)
+    }
+
+    fn with_decoder(
+        decoder: &'static str,
+        backend: Option<JsonBackend>,
+        err: &dyn fmt::Display,
+        path: Option<String>,
+        offset: Option<usize>,
+        body: &[u8],
+    ) -> Self {
+        let (start, end) = match offset {
+            Some(offset) => (
+                offset.saturating_sub(EXCERPT_CONTEXT),
+                (offset + EXCERPT_CONTEXT).min(body.len()),
+            ),
+            None => (0, (2 * EXCERPT_CONTEXT).min(body.len())),
+        };
+        JsonError {
+            decoder,
+            backend,
+            message: err.to_string(),
+            path,
+            offset,
+            excerpt: String::from_utf8_lossy(&body[start.min(end)..end]).into_owned(),
+        }
+    }
+
+    /// Backend of the error, `None` for a custom `DynJsonDecoder`.
+    #[inline]
+    pub fn backend(&self) -> Option<JsonBackend> {
+        self.backend
+    }
+
+    /// Name of the backend or of the custom decoder.
+    #[inline]
+    pub fn decoder(&self) -> &'static str {
+        self.decoder
+    }
+
+    /// Error message of the decoder.
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Path of the offending value, e.g. `symbols[3].filters[0].minPrice`.
+    #[inline]
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    /// Byte offset in the body the backend stopped at, or of the value at `path`.
+    #[inline]
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Part of the body around the offset, or its start.
+    #[inline]
+    pub fn excerpt(&self) -> &str {
+        &self.excerpt
+    }
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.decoder, self.message)?;
+        if let Some(path) = self.path.as_ref() {
+            write!(f, " at `{}`", path)?;
+        }
+        write!(f, " near `{}`", self.excerpt)
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::schemes::spot::market::OrderBook;
+
+    const BODY: &str = "{\n  \"lastUpdateId\": 1027024,\n  \"bids\": [[\"4.00000000\", \"431.00000000\"]],\n  \"asks\": [[\"4.00000200\", \"12.0000000x\"]]\n}";
+
+    fn backends() -> Vec<JsonBackend> {
+        vec![
+            #[cfg(feature = "serde_json")]
+            JsonBackend::SerdeJson,
+            #[cfg(feature = "simd_json")]
+            JsonBackend::SimdJson,
+            #[cfg(feature = "sonic_rs")]
+            JsonBackend::SonicRs,
+        ]
+    }
+
+    #[test]
+    fn test_decode_error_has_path_and_excerpt() {
+        for backend in backends() {
+            let err = backend.decode::<OrderBook>(BODY.as_bytes()).unwrap_err();
+            assert_eq!(err.backend(), Some(backend));
+            assert_eq!(err.path(), Some("asks[0][1]"), "{}", backend);
+            assert!(err.excerpt().contains("12.0000000x"), "{}: {}", backend, err);
+        }
+    }
+
+    #[test]
+    fn test_decode_with_every_backend() {
+        let body = BODY.replace("12.0000000x", "12.00000000");
+        for backend in backends() {
+            let book: OrderBook = backend.decode(body.as_bytes()).unwrap();
+            assert_eq!(book.last_update_id, 1027024);
+            assert_eq!(book.asks[0].qty, "12".parse().unwrap());
+        }
+    }
+
+    #[cfg(feature = "simd_json")]
+    #[test]
+    fn test_simd_json_decoder_reuses_buffers() {
+        let body = BODY.replace("12.0000000x", "12.00000000");
+        let decoder = SimdJsonDecoder::default();
+        assert_eq!(decoder.pooled(), 0);
+
+        for _ in 0..3 {
+            let book: OrderBook = decoder.decode(body.as_bytes()).unwrap();
+            assert_eq!(book.bids[0].qty, "431".parse().unwrap());
+            assert_eq!(decoder.pooled(), 1);
+        }
+
+        // a failed decode gives its buffers back too, and the body is left as it was
+        let bad = BODY.as_bytes().to_vec();
+        decoder.decode::<OrderBook>(&bad).unwrap_err();
+        assert_eq!(bad, BODY.as_bytes());
+        assert_eq!(decoder.clone().pooled(), 1);
+    }
+
+    #[test]
+    fn test_offset_of_path() {
+        let body = br#"{"symbols": [{"s": "a\"]", "filters": [{}, {"minPrice": "0.1"}]}]}"#;
+        let offset = offset_of_path(body, "symbols[0].filters[1].minPrice").unwrap();
+        assert_eq!(&body[offset..offset + 5], br#""0.1""#);
+        assert_eq!(offset_of_path(BODY.as_bytes(), "asks[0][1]"), BODY.find("\"12.0000000x"));
+        assert_eq!(offset_of_path(body, "symbols[1]"), None);
+        assert_eq!(offset_of_path(body, "symbols[0].missing"), None);
+    }
 
-        simd_json::from_slice(&mut json_slice)
+    #[cfg(any(feature = "serde_json", feature = "sonic_rs"))]
+    #[test]
+    fn test_offset_of_line_and_column() {
+        let body = b"{\n  \"a\": x\n}";
+        assert_eq!(offset_of(body, 2, 8), Some(9));
+        assert_eq!(body[9], b'x');
+        assert_eq!(offset_of(body, 5, 1), None);
     }
 }
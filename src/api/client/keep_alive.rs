@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+use super::BinanceClient;
+
+/// Opens the connections right away and pings them every `interval`,
+/// before the server or the pool closes them as idle.
+pub(crate) async fn run_keep_alive(
+    client: BinanceClient,
+    path: &'static str,
+    connections: usize,
+    interval: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        client.warm_up(path, connections).await;
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use http::Method;
+use reqwest::StatusCode;
+
+/// Upper bounds of the latency histogram buckets, in seconds.
+pub const LATENCY_BUCKETS: [f64; 11] = [0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0];
+
+/// One finished request, passed to `MetricsSink::record`.
+#[derive(Clone, Debug)]
+pub struct RequestMetrics<'a> {
+    pub host: &'a str,
+    pub method: &'a Method,
+    pub path: &'a str,
+    /// `None` if no response was received.
+    pub status: Option<StatusCode>,
+    /// Latency of the last attempt.
+    pub latency: Duration,
+    pub used_weight_1m: Option<u16>,
+    /// `code` of a Binance error response.
+    pub error_code: Option<i64>,
+}
+
+/// Receives metrics of every request made by the client.
+pub trait MetricsSink: Send + Sync {
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct LatencyHistogram {
+    /// Cumulative counts per bucket of `LATENCY_BUCKETS`.
+    pub buckets: [u64; LATENCY_BUCKETS.len()],
+    pub sum_secs: f64,
+    pub count: u64,
+}
+
+impl LatencyHistogram {
+    fn observe(&mut self, latency: Duration) {
+        let secs = latency.as_secs_f64();
+        for (bucket, bound) in self.buckets.iter_mut().zip(LATENCY_BUCKETS) {
+            if secs <= bound {
+                *bucket += 1;
+            }
+        }
+        self.sum_secs += secs;
+        self.count += 1;
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// (method, path, status) -> count, status is empty for requests without response.
+    requests: BTreeMap<(String, String, String), u64>,
+    latencies: BTreeMap<(String, String), LatencyHistogram>,
+    used_weight_1m: BTreeMap<String, u16>,
+    error_codes: BTreeMap<i64, u64>,
+}
+
+/// In-process metrics sink that renders the Prometheus text format.
+///
+/// One registry can be shared by several clients, the used weight is kept per host.
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    state: Mutex<State>,
+}
+
+impl MetricsRegistry {
+    pub fn new() -> Self {
+        MetricsRegistry::default()
+    }
+
+    pub fn request_count(&self, method: &Method, path: &str, status: Option<StatusCode>) -> u64 {
+        let key = (method.to_string(), path.to_owned(), status_label(status));
+        self.state.lock().unwrap().requests.get(&key).copied().unwrap_or(0)
+    }
+
+    pub fn latency(&self, method: &Method, path: &str) -> Option<LatencyHistogram> {
+        let key = (method.to_string(), path.to_owned());
+        self.state.lock().unwrap().latencies.get(&key).cloned()
+    }
+
+    /// Last `x-mbx-used-weight-1m` seen from the host.
+    pub fn used_weight_1m(&self, host: &str) -> Option<u16> {
+        self.state.lock().unwrap().used_weight_1m.get(host).copied()
+    }
+
+    pub fn error_code_count(&self, code: i64) -> u64 {
+        self.state.lock().unwrap().error_codes.get(&code).copied().unwrap_or(0)
+    }
+
+    pub fn render_prometheus(&self) -> String {
+        let state = self.state.lock().unwrap();
+        let mut out = String::new();
+
+        out.push_str("# HELP binance_requests_total Requests sent to the Binance API.\n");
+        out.push_str("# TYPE binance_requests_total counter\n");
+        for ((method, path, status), count) in state.requests.iter() {
+            let _ = writeln!(
+                out,
+                "binance_requests_total{{method=\"{}\",path=\"{}\",status=\"{}\"}} {}",
+                escape(method),
+                escape(path),
+                escape(status),
+                count,
+            );
+        }
+
+        out.push_str("# HELP binance_request_duration_seconds Latency of Binance API requests.\n");
+        out.push_str("# TYPE binance_request_duration_seconds histogram\n");
+        for ((method, path), histogram) in state.latencies.iter() {
+            let labels = format!("method=\"{}\",path=\"{}\"", escape(method), escape(path));
+            for (bound, count) in LATENCY_BUCKETS.iter().zip(histogram.buckets.iter()) {
+                let _ = writeln!(
+                    out,
+                    "binance_request_duration_seconds_bucket{{{},le=\"{}\"}} {}",
+                    labels, bound, count,
+                );
+            }
+            let _ = writeln!(
+                out,
+                "binance_request_duration_seconds_bucket{{{},le=\"+Inf\"}} {}",
+                labels, histogram.count,
+            );
+            let _ = writeln!(out, "binance_request_duration_seconds_sum{{{}}} {}", labels, histogram.sum_secs);
+            let _ = writeln!(out, "binance_request_duration_seconds_count{{{}}} {}", labels, histogram.count);
+        }
+
+        out.push_str("# HELP binance_used_weight_1m Last seen x-mbx-used-weight-1m.\n");
+        out.push_str("# TYPE binance_used_weight_1m gauge\n");
+        for (host, used) in state.used_weight_1m.iter() {
+            let _ = writeln!(out, "binance_used_weight_1m{{host=\"{}\"}} {}", escape(host), used);
+        }
+
+        out.push_str("# HELP binance_errors_total Binance error responses by error code.\n");
+        out.push_str("# TYPE binance_errors_total counter\n");
+        for (code, count) in state.error_codes.iter() {
+            let _ = writeln!(out, "binance_errors_total{{code=\"{}\"}} {}", code, count);
+        }
+
+        out
+    }
+}
+
+impl MetricsSink for MetricsRegistry {
+    fn record(&self, metrics: &RequestMetrics) {
+        let mut state = self.state.lock().unwrap();
+        let method = metrics.method.to_string();
+
+        *state
+            .requests
+            .entry((method.clone(), metrics.path.to_owned(), status_label(metrics.status)))
+            .or_default() += 1;
+        state
+            .latencies
+            .entry((method, metrics.path.to_owned()))
+            .or_default()
+            .observe(metrics.latency);
+        if let Some(used) = metrics.used_weight_1m {
+            state.used_weight_1m.insert(metrics.host.to_owned(), used);
+        }
+        if let Some(code) = metrics.error_code {
+            *state.error_codes.entry(code).or_default() += 1;
+        }
+    }
+}
+
+#[inline]
+fn status_label(status: Option<StatusCode>) -> String {
+    status.map(|s| s.as_str().to_owned()).unwrap_or_default()
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_prometheus() {
+        let registry = MetricsRegistry::new();
+        let ok = RequestMetrics {
+            host: "https://api.binance.com",
+            method: &Method::GET,
+            path: "/api/v3/ping",
+            status: Some(StatusCode::OK),
+            latency: Duration::from_millis(20),
+            used_weight_1m: Some(7),
+            error_code: None,
+        };
+        registry.record(&ok);
+        registry.record(&RequestMetrics {
+            status: Some(StatusCode::BAD_REQUEST),
+            latency: Duration::from_millis(200),
+            used_weight_1m: Some(8),
+            error_code: Some(-1121),
+            ..ok.clone()
+        });
+
+        assert_eq!(registry.request_count(&Method::GET, "/api/v3/ping", Some(StatusCode::OK)), 1);
+        assert_eq!(registry.used_weight_1m("https://api.binance.com"), Some(8));
+        assert_eq!(registry.error_code_count(-1121), 1);
+
+        let text = registry.render_prometheus();
+        assert!(text.contains(
+            "binance_requests_total{method=\"GET\",path=\"/api/v3/ping\",status=\"400\"} 1\n"
+        ));
+        assert!(text.contains(
+            "binance_request_duration_seconds_bucket{method=\"GET\",path=\"/api/v3/ping\",le=\"0.025\"} 1\n"
+        ));
+        assert!(text.contains(
+            "binance_request_duration_seconds_bucket{method=\"GET\",path=\"/api/v3/ping\",le=\"+Inf\"} 2\n"
+        ));
+        assert!(text.contains(
+            "binance_request_duration_seconds_count{method=\"GET\",path=\"/api/v3/ping\"} 2\n"
+        ));
+        assert!(text.contains("binance_used_weight_1m{host=\"https://api.binance.com\"} 8\n"));
+        assert!(text.contains("binance_errors_total{code=\"-1121\"} 1\n"));
+    }
+}
@@ -1,17 +1,55 @@
+pub mod clock;
+pub mod credentials;
 pub mod error;
 mod hmac;
-mod json;
+pub mod hosts;
+pub mod interceptor;
+pub mod json;
+mod keep_alive;
+pub mod metrics;
+pub mod options;
+pub mod proxy;
 pub(crate) mod query_string;
+pub mod rate_limit;
+pub mod retry;
+pub mod signer;
+pub mod source_ip;
 pub mod response;
+mod tasks;
+pub mod time_sync;
+#[cfg(feature = "tracing")]
+mod trace;
+pub mod transport;
 pub(crate) mod url;
 
 use std::net::IpAddr;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use crate::api::client::clock::{Clock, SystemClock};
+use crate::api::client::credentials::{ApiKey, SecretString};
 use crate::api::client::error::BinanceError;
 use crate::api::client::response::{BinanceHttpHeader, Response};
+use crate::schemes::common::general::RateLimit;
+use crate::schemes::common::ServerTime;
+use crate::types::time::TimeUnit;
+use hosts::{run_host_health_check, HostPool, HostSelection};
+use interceptor::{Interceptor, RequestParts, ResponseMeta};
+use json::{ClientJsonDecoder, DynJsonDecoder, JsonBackend, JsonDecoder};
+use keep_alive::run_keep_alive;
+use metrics::{MetricsSink, RequestMetrics};
+use options::RequestOptions;
+use proxy::ProxyConfig;
+use rate_limit::{RateLimitMode, RateLimiter, WeightLimit};
+use retry::{is_idempotent, RetryEvent, RetryPolicy, RetryReason};
+use signer::{HmacSigner, Signer, SignerError, SigningKey};
+use source_ip::{SourceIp, SourceIpPool};
+use tasks::BackgroundTasks;
+use time_sync::{ServerTimeSync, TimeOffset};
+use transport::{ReqwestTransport, Transport, TransportErrorKind, TransportRequest, TransportResponse};
 use url::Url;
 
+use bytes::Bytes;
 use http::Method;
 use reqwest::{
     header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE},
@@ -25,36 +63,101 @@ pub(crate) static RECV_WINDOW_MS_DEFAULT: u16 = 5000;
 
 const RECV_WINDOW_MAX_LEN: usize = 17;
 const TIMESTAMP_MAX_LEN: usize = 30;
-const SIGNATURE_MAX_LEN: usize = 64 + 11;
+const SIGNATURE_PARAM_LEN: usize = 11;
 const SIGNED_PARAMS_LEN: usize =
-    RECV_WINDOW_MAX_LEN + TIMESTAMP_MAX_LEN + SIGNATURE_MAX_LEN;
+    RECV_WINDOW_MAX_LEN + TIMESTAMP_MAX_LEN + SIGNATURE_PARAM_LEN;
 
 pub struct BinanceClientBuilder {
     pub host: String,
-    pub api_key: Option<String>,
-    pub secret_key: Option<String>,
+    /// Alternates of `host`, in the order of preference. Requests fail over to them.
+    pub fallback_hosts: Vec<String>,
+    pub host_selection: HostSelection,
+    /// `ping` path and interval of the host health checks.
+    pub host_health_check: Option<(&'static str, Duration)>,
+    /// Validated by `build`.
+    pub api_key: Option<SecretString>,
+    pub signing_key: Option<SigningKey>,
     pub recv_window: Option<u16>,
+    /// Resolution of the response timestamps and of the `timestamp` param, spot only.
+    pub time_unit: TimeUnit,
     pub http_connect_timeout_ms: Option<Duration>,
     pub http_request_timeout_ms: Option<Duration>,
     pub tcp_nodelay: Option<bool>,
+    /// Speaks HTTP/2 from the start of every connection instead of HTTP/1.1.
+    pub http2_prior_knowledge: bool,
+    pub pool_idle_timeout: Option<Duration>,
+    pub pool_max_idle_per_host: Option<usize>,
+    /// `ping` path and number of connections opened when the client is built.
+    pub warm_up: Option<(&'static str, usize)>,
+    /// Interval of the `ping` calls keeping the `warm_up` connections open.
+    pub keep_alive_interval: Option<Duration>,
     pub local_addr: Option<IpAddr>,
+    /// Several source addresses, each request is sent from the one with the most weight headroom.
+    /// `local_addr`, if set, is added to them.
+    pub local_addrs: Vec<IpAddr>,
+    pub proxy: Option<ProxyConfig>,
+    pub server_time_sync: Option<(&'static str, Duration)>,
+    /// Local time of the signed timestamps and of the server time sync, the system clock by default.
+    pub clock: Option<Arc<dyn Clock>>,
+    pub rate_limiter: Option<RateLimiter>,
+    /// Order count of the account, see `RateLimiter::orders`.
+    pub order_limiter: Option<RateLimiter>,
+    pub retry_policy: Option<RetryPolicy>,
+    /// Replaces the default reqwest transport, the HTTP options above are not used then.
+    pub transport: Option<Arc<dyn Transport>>,
+    pub interceptors: Vec<Arc<dyn Interceptor>>,
+    pub metrics: Option<Arc<dyn MetricsSink>>,
+    /// Keeps the body of every response in `Response::raw`.
+    pub keep_raw_body: bool,
+    pub json_backend: JsonBackend,
+    /// Replaces the `json_backend`.
+    pub json_decoder: Option<Arc<dyn DynJsonDecoder>>,
 }
 
 impl BinanceClientBuilder {
     pub fn new(host: String) -> Self {
         BinanceClientBuilder {
             host,
+            fallback_hosts: Vec::new(),
+            host_selection: HostSelection::default(),
+            host_health_check: None,
             api_key: None,
-            secret_key: None,
+            signing_key: None,
             recv_window: None,
+            time_unit: TimeUnit::default(),
             http_connect_timeout_ms: None,
             http_request_timeout_ms: None,
             tcp_nodelay: None,
+            http2_prior_knowledge: false,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            warm_up: None,
+            keep_alive_interval: None,
             local_addr: None,
+            local_addrs: Vec::new(),
+            proxy: None,
+            server_time_sync: None,
+            clock: None,
+            rate_limiter: None,
+            order_limiter: None,
+            retry_policy: None,
+            transport: None,
+            interceptors: Vec::new(),
+            metrics: None,
+            keep_raw_body: false,
+            json_backend: JsonBackend::default(),
+            json_decoder: None,
         }
     }
 
-    pub fn build(self) -> Result<BinanceClient, BinanceError<()>> {
+    fn build_transport(&self) -> Result<Arc<dyn Transport>, BinanceError<()>> {
+        if let Some(transport) = self.transport.as_ref() {
+            return Ok(transport.clone());
+        }
+        self.build_reqwest_transport(self.local_addr)
+    }
+
+    fn build_reqwest_transport(&self, local_addr: Option<IpAddr>) -> Result<Arc<dyn Transport>, BinanceError<()>> {
         let mut client = Client::builder();
 
         let http_connect_timeout = self
@@ -69,20 +172,123 @@ impl BinanceClientBuilder {
 
         client = client.tcp_nodelay(self.tcp_nodelay.unwrap_or(true));
 
-        if let Some(addr) = self.local_addr {
+        if self.http2_prior_knowledge {
+            client = client.http2_prior_knowledge();
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            client = client.pool_idle_timeout(timeout);
+        }
+        if let Some(max_idle) = self.pool_max_idle_per_host {
+            client = client.pool_max_idle_per_host(max_idle);
+        }
+
+        if let Some(addr) = local_addr {
             client = client.local_address(addr);
         }
 
-        Ok(BinanceClient {
-            api_key: self.api_key.unwrap_or(String::new()),
-            secret_key: {
-                let key = self.secret_key.unwrap_or_default();
-                ring::hmac::Key::new(ring::hmac::HMAC_SHA256, key.as_bytes())
+        if let Some(proxy) = self.proxy.as_ref() {
+            client = client.proxy(proxy.to_reqwest()?);
+        }
+
+        Ok(Arc::new(ReqwestTransport::new(client.build()?)))
+    }
+
+    /// One reqwest client and weight budget per local address, not used with a custom transport.
+    fn build_source_ips(&self) -> Result<Option<SourceIpPool>, BinanceError<()>> {
+        if self.local_addrs.is_empty() || self.transport.is_some() {
+            return Ok(None);
+        }
+
+        // Without client side rate limiting the weight is only counted to pick the address.
+        let (mode, limits) = match self.rate_limiter.as_ref() {
+            Some(limiter) => (limiter.mode(), limiter.limits()),
+            None => (RateLimitMode::Wait, vec![WeightLimit::per_minute(u32::MAX)]),
+        };
+        let addrs = self
+            .local_addr
+            .filter(|addr| !self.local_addrs.contains(addr))
+            .into_iter()
+            .chain(self.local_addrs.iter().copied());
+        let sources = addrs
+            .map(|addr| {
+                Ok(SourceIp {
+                    addr,
+                    transport: self.build_reqwest_transport(Some(addr))?,
+                    limiter: RateLimiter::new(mode, &limits),
+                })
+            })
+            .collect::<Result<Vec<_>, BinanceError<()>>>()?;
+
+        Ok(Some(SourceIpPool::new(sources, self.rate_limiter.is_some())))
+    }
+
+    pub fn build(self) -> Result<BinanceClient, BinanceError<()>> {
+        let source_ips = self.build_source_ips()?.map(Arc::new);
+        let transport = match source_ips.as_ref() {
+            Some(pool) => pool.sources()[0].transport.clone(),
+            None => self.build_transport()?,
+        };
+        let hosts = if self.fallback_hosts.is_empty() {
+            None
+        } else {
+            let hosts = std::iter::once(self.host.clone()).chain(self.fallback_hosts);
+            Some(Arc::new(HostPool::new(hosts, self.host_selection)))
+        };
+
+        let mut binance_client = BinanceClient {
+            api_key: self.api_key.map(ApiKey::new).transpose()?,
+            signer: match self.signing_key {
+                Some(signing_key) => signing_key.into_signer()?,
+                None => Arc::new(HmacSigner::new(b"")),
             },
             recv_window: self.recv_window.unwrap_or(RECV_WINDOW_MS_DEFAULT),
-            host: self.host,
-            client: client.build()?,
-        })
+            time_unit: self.time_unit,
+            host: self.host.into(),
+            hosts,
+            transport,
+            time_sync: Arc::new(ServerTimeSync::default()),
+            clock: self.clock.unwrap_or_else(|| Arc::new(SystemClock)),
+            // with several source addresses every one has its own limiter
+            rate_limiter: self.rate_limiter.filter(|_| source_ips.is_none()).map(Arc::new),
+            source_ips,
+            order_limiter: self.order_limiter.map(Arc::new),
+            proxy: self.proxy.map(Arc::new),
+            retry_policy: self.retry_policy.unwrap_or_else(RetryPolicy::none),
+            interceptors: Arc::new(self.interceptors),
+            metrics: self.metrics,
+            keep_raw_body: self.keep_raw_body,
+            json: ClientJsonDecoder::new(self.json_backend, self.json_decoder),
+            tasks: None,
+        };
+
+        let mut tasks = BackgroundTasks::default();
+        if let Some((path, interval)) = self.server_time_sync {
+            let runtime = tokio::runtime::Handle::try_current()?;
+            tasks.push(runtime.spawn(time_sync::run_server_time_sync(
+                binance_client.clone(),
+                path,
+                interval,
+            )));
+        }
+        if let (Some((path, interval)), Some(_)) = (self.host_health_check, binance_client.hosts.as_ref()) {
+            let runtime = tokio::runtime::Handle::try_current()?;
+            tasks.push(runtime.spawn(run_host_health_check(binance_client.clone(), path, interval)));
+        }
+        if let Some((path, connections)) = self.warm_up {
+            let runtime = tokio::runtime::Handle::try_current()?;
+            let client = binance_client.clone();
+            tasks.push(match self.keep_alive_interval {
+                Some(interval) => runtime.spawn(run_keep_alive(client, path, connections, interval)),
+                None => runtime.spawn(async move {
+                    client.warm_up(path, connections).await;
+                }),
+            });
+        }
+        if !tasks.is_empty() {
+            binance_client.tasks = Some(Arc::new(tasks));
+        }
+
+        Ok(binance_client)
     }
 }
 
@@ -91,7 +297,6 @@ fn http_client_default() -> ClientBuilder {
     headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
 
     Client::builder()
-        // .http2_prior_knowledge()
         // .https_only(true)
         .tcp_nodelay(true)
         .connect_timeout(Duration::from_millis(HTTP_CONNECTION_TIMEOUT_MS_DEFAULT))
@@ -99,13 +304,60 @@ fn http_client_default() -> ClientBuilder {
         .default_headers(headers)
 }
 
+/// Round-trip time of a successful `GET`, without interceptors, retries or metrics.
+async fn ping(transport: &dyn Transport, url: &str) -> Option<Duration> {
+    let headers = HeaderMap::new();
+
+    let started = Instant::now();
+    let resp = transport
+        .send(TransportRequest {
+            method: &Method::GET,
+            url,
+            headers: &headers,
+            body: None,
+            timeout: None,
+        })
+        .await
+        .ok()?;
+
+    resp.status.is_success().then(|| started.elapsed())
+}
+
+/// Final response of a request, accounted but not decoded yet.
+struct Received {
+    resp: TransportResponse,
+    host: Arc<str>,
+    headers: BinanceHttpHeader,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Security {
+    None,
+    ApiKey,
+    Signed,
+}
+
 #[derive(Clone)]
 pub struct BinanceClient {
-    pub(crate) api_key: String,
-    pub(crate) secret_key: ring::hmac::Key,
+    pub(crate) api_key: Option<ApiKey>,
+    pub(crate) signer: Arc<dyn Signer>,
     pub(crate) recv_window: u16,
-    pub(crate) host: String,
-    pub(crate) client: Client,
+    pub(crate) time_unit: TimeUnit,
+    pub(crate) host: Arc<str>,
+    pub(crate) hosts: Option<Arc<HostPool>>,
+    pub(crate) transport: Arc<dyn Transport>,
+    pub(crate) time_sync: Arc<ServerTimeSync>,
+    pub(crate) clock: Arc<dyn Clock>,
+    pub(crate) rate_limiter: Option<Arc<RateLimiter>>,
+    pub(crate) source_ips: Option<Arc<SourceIpPool>>,
+    pub(crate) order_limiter: Option<Arc<RateLimiter>>,
+    pub(crate) proxy: Option<Arc<ProxyConfig>>,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) interceptors: Arc<Vec<Arc<dyn Interceptor>>>,
+    pub(crate) metrics: Option<Arc<dyn MetricsSink>>,
+    pub(crate) keep_raw_body: bool,
+    pub(crate) json: ClientJsonDecoder,
+    pub(crate) tasks: Option<Arc<BackgroundTasks>>,
 }
 
 impl BinanceClient {
@@ -115,25 +367,53 @@ impl BinanceClient {
 
     pub fn new(host: String) -> Result<Self, BinanceError<()>> {
         Ok(Self {
-            api_key: String::new(),
-            secret_key: ring::hmac::Key::new(ring::hmac::HMAC_SHA256, String::new().as_bytes()),
+            api_key: None,
+            signer: Arc::new(HmacSigner::new(b"")),
             recv_window: RECV_WINDOW_MS_DEFAULT,
-            host,
-            client: http_client_default().build()?,
+            time_unit: TimeUnit::Millisecond,
+            host: host.into(),
+            hosts: None,
+            transport: Arc::new(ReqwestTransport::new(http_client_default().build()?)),
+            time_sync: Arc::new(ServerTimeSync::default()),
+            clock: Arc::new(SystemClock),
+            rate_limiter: None,
+            source_ips: None,
+            order_limiter: None,
+            proxy: None,
+            retry_policy: RetryPolicy::none(),
+            interceptors: Arc::default(),
+            metrics: None,
+            keep_raw_body: false,
+            json: ClientJsonDecoder::default(),
+            tasks: None,
         })
     }
 
     pub fn signed(
         host: String,
-        api_key: String,
-        secret_key: String,
+        api_key: SecretString,
+        secret_key: SecretString,
     ) -> Result<Self, BinanceError<()>> {
         Ok(Self {
-            api_key,
-            secret_key: ring::hmac::Key::new(ring::hmac::HMAC_SHA256, secret_key.as_bytes()),
+            api_key: Some(ApiKey::new(api_key)?),
+            signer: SigningKey::Hmac(secret_key).into_signer()?,
             recv_window: RECV_WINDOW_MS_DEFAULT,
-            host,
-            client: http_client_default().build()?,
+            time_unit: TimeUnit::Millisecond,
+            host: host.into(),
+            hosts: None,
+            transport: Arc::new(ReqwestTransport::new(http_client_default().build()?)),
+            time_sync: Arc::new(ServerTimeSync::default()),
+            clock: Arc::new(SystemClock),
+            rate_limiter: None,
+            source_ips: None,
+            order_limiter: None,
+            proxy: None,
+            retry_policy: RetryPolicy::none(),
+            interceptors: Arc::default(),
+            metrics: None,
+            keep_raw_body: false,
+            json: ClientJsonDecoder::default(),
+            tasks: None,
         })
     }
 
@@ -160,7 +440,12 @@ impl BinanceClient {
     //     self.request(Method::GET, url).await
     // }
 
-    pub async fn get<D, E>(&self, path: &str) -> Result<Response<D>, BinanceError<E>>
+    pub async fn get<D, E>(
+        &self,
+        path: &str,
+        weight: u32,
+        opts: &RequestOptions,
+    ) -> Result<Response<D>, BinanceError<E>>
     where
         D: for<'de> Deserialize<'de>,
         E: for<'de> Deserialize<'de>,
@@ -168,15 +453,14 @@ impl BinanceClient {
         let url_capacity = self.host.len() + path.len();
         let url = Url::with_capacity(&self.host, path, url_capacity);
 
-        #[cfg(debug_assertions)]
-        debug_url("final", &url);
-
-        self.request(Method::GET, url).await
+        self.send(Method::GET, url, weight, Security::None, opts).await
     }
 
     pub async fn get_with_query<D, E>(
         &self,
         path: &str,
+        weight: u32,
+        opts: &RequestOptions,
         query_capacity: usize,
         add_params: impl FnOnce(&mut Url),
     ) -> Result<Response<D>, BinanceError<E>>
@@ -185,21 +469,52 @@ impl BinanceClient {
         E: for<'de> Deserialize<'de>,
     {
         let url_capacity = self.host.len() + path.len() + query_capacity;
-        #[cfg(debug_assertions)]
-        println!("start url capacity: {}", url_capacity);
-
         let mut url = Url::with_capacity(&self.host, path, url_capacity);
         add_params(&mut url);
 
-        #[cfg(debug_assertions)]
-        debug_url("final", &url);
+        self.send(Method::GET, url, weight, Security::None, opts).await
+    }
+
+    /// [`get`](Self::get) with the body of a successful response left undecoded.
+    pub async fn get_raw<E>(
+        &self,
+        path: &str,
+        weight: u32,
+        opts: &RequestOptions,
+    ) -> Result<Response<Bytes>, BinanceError<E>>
+    where
+        E: for<'de> Deserialize<'de>,
+    {
+        let url_capacity = self.host.len() + path.len();
+        let url = Url::with_capacity(&self.host, path, url_capacity);
+
+        self.send_raw(Method::GET, url, weight, Security::None, opts).await
+    }
 
-        self.request(Method::GET, url).await
+    /// [`get_with_query`](Self::get_with_query) with the body of a successful response left undecoded.
+    pub async fn get_with_query_raw<E>(
+        &self,
+        path: &str,
+        weight: u32,
+        opts: &RequestOptions,
+        query_capacity: usize,
+        add_params: impl FnOnce(&mut Url),
+    ) -> Result<Response<Bytes>, BinanceError<E>>
+    where
+        E: for<'de> Deserialize<'de>,
+    {
+        let url_capacity = self.host.len() + path.len() + query_capacity;
+        let mut url = Url::with_capacity(&self.host, path, url_capacity);
+        add_params(&mut url);
+
+        self.send_raw(Method::GET, url, weight, Security::None, opts).await
     }
 
     pub async fn get_with_query_api_key<D, E>(
         &self,
         path: &str,
+        weight: u32,
+        opts: &RequestOptions,
         query_capacity: usize,
         add_params: impl FnOnce(&mut Url),
     ) -> Result<Response<D>, BinanceError<E>>
@@ -208,36 +523,33 @@ impl BinanceClient {
         E: for<'de> Deserialize<'de>,
     {
         let url_capacity = self.host.len() + path.len() + query_capacity;
-        #[cfg(debug_assertions)]
-        println!("start url capacity: {}", url_capacity);
-
         let mut url = Url::with_capacity(&self.host, path, url_capacity);
         add_params(&mut url);
 
-        #[cfg(debug_assertions)]
-        debug_url("final", &url);
-
-        self.signed_request(Method::GET, url).await
+        self.send(Method::GET, url, weight, Security::ApiKey, opts).await
     }
 
-    pub async fn get_signed<D, E>(&self, path: &str) -> Result<Response<D>, BinanceError<E>>
+    pub async fn get_signed<D, E>(
+        &self,
+        path: &str,
+        weight: u32,
+        opts: &RequestOptions,
+    ) -> Result<Response<D>, BinanceError<E>>
     where
         D: for<'de> Deserialize<'de>,
         E: for<'de> Deserialize<'de>,
     {
-        let url_capacity = self.host.len() + path.len() + SIGNED_PARAMS_LEN;
-        let mut url = Url::with_capacity(&self.host, path, url_capacity);
-        self.add_signed_params(&mut url);
-
-        #[cfg(debug_assertions)]
-        debug_url("final", &url);
+        let url_capacity = self.host.len() + path.len() + self.signed_params_len();
+        let url = Url::with_capacity(&self.host, path, url_capacity);
 
-        self.signed_request(Method::GET, url).await
+        self.send(Method::GET, url, weight, Security::Signed, opts).await
     }
 
     pub async fn get_signed_with_query<D, E>(
         &self,
         path: &str,
+        weight: u32,
+        opts: &RequestOptions,
         query_capacity: usize,
         add_params: impl FnOnce(&mut Url) -> Result<(), serde_qs::Error>,
     ) -> Result<Response<D>, BinanceError<E>>
@@ -245,23 +557,18 @@ impl BinanceClient {
         D: for<'de> Deserialize<'de>,
         E: for<'de> Deserialize<'de>,
     {
-        let url_capacity = self.host.len() + path.len() + query_capacity + SIGNED_PARAMS_LEN;
-        #[cfg(debug_assertions)]
-        println!("start url capacity: {}", url_capacity);
-
+        let url_capacity = self.host.len() + path.len() + query_capacity + self.signed_params_len();
         let mut url = Url::with_capacity(&self.host, path, url_capacity);
         add_params(&mut url)?;
-        self.add_signed_params(&mut url);
-
-        #[cfg(debug_assertions)]
-        debug_url("final", &url);
 
-        self.signed_request(Method::GET, url).await
+        self.send(Method::GET, url, weight, Security::Signed, opts).await
     }
 
     pub async fn post_signed_with_query<D, E>(
         &self,
         path: &str,
+        weight: u32,
+        opts: &RequestOptions,
         query_capacity: usize,
         add_params: impl FnOnce(&mut Url) -> Result<(), serde_qs::Error>,
     ) -> Result<Response<D>, BinanceError<E>>
@@ -269,23 +576,18 @@ impl BinanceClient {
         D: for<'de> Deserialize<'de>,
         E: for<'de> Deserialize<'de>,
     {
-        let url_capacity = self.host.len() + path.len() + query_capacity + SIGNED_PARAMS_LEN;
-        #[cfg(debug_assertions)]
-        println!("start url capacity: {}", url_capacity);
-
+        let url_capacity = self.host.len() + path.len() + query_capacity + self.signed_params_len();
         let mut url = Url::with_capacity(&self.host, path, url_capacity);
         add_params(&mut url)?;
-        self.add_signed_params(&mut url);
-
-        #[cfg(debug_assertions)]
-        debug_url("final", &url);
 
-        self.signed_request(Method::POST, url).await
+        self.send(Method::POST, url, weight, Security::Signed, opts).await
     }
 
     pub async fn delete_signed_with_query<D, E>(
         &self,
         path: &str,
+        weight: u32,
+        opts: &RequestOptions,
         query_capacity: usize,
         add_params: impl FnOnce(&mut Url) -> Result<(), serde_qs::Error>,
     ) -> Result<Response<D>, BinanceError<E>>
@@ -293,84 +595,816 @@ impl BinanceClient {
         D: for<'de> Deserialize<'de>,
         E: for<'de> Deserialize<'de>,
     {
-        let url_capacity = self.host.len() + path.len() + query_capacity + SIGNED_PARAMS_LEN;
-        #[cfg(debug_assertions)]
-        println!("start url capacity: {}", url_capacity);
+        let url_capacity = self.host.len() + path.len() + query_capacity + self.signed_params_len();
+        let mut url = Url::with_capacity(&self.host, path, url_capacity);
+        add_params(&mut url)?;
+
+        self.send(Method::DELETE, url, weight, Security::Signed, opts).await
+    }
+
+    pub async fn post_signed_with_body<D, E>(
+        &self,
+        path: &str,
+        weight: u32,
+        opts: &RequestOptions,
+        body_capacity: usize,
+        add_params: impl FnOnce(&mut Url) -> Result<(), serde_qs::Error>,
+    ) -> Result<Response<D>, BinanceError<E>>
+    where
+        D: for<'de> Deserialize<'de>,
+        E: for<'de> Deserialize<'de>,
+    {
+        self.send_signed_with_body(Method::POST, path, weight, opts, body_capacity, add_params).await
+    }
 
+    pub async fn put_signed_with_body<D, E>(
+        &self,
+        path: &str,
+        weight: u32,
+        opts: &RequestOptions,
+        body_capacity: usize,
+        add_params: impl FnOnce(&mut Url) -> Result<(), serde_qs::Error>,
+    ) -> Result<Response<D>, BinanceError<E>>
+    where
+        D: for<'de> Deserialize<'de>,
+        E: for<'de> Deserialize<'de>,
+    {
+        self.send_signed_with_body(Method::PUT, path, weight, opts, body_capacity, add_params).await
+    }
+
+    pub async fn delete_signed_with_body<D, E>(
+        &self,
+        path: &str,
+        weight: u32,
+        opts: &RequestOptions,
+        body_capacity: usize,
+        add_params: impl FnOnce(&mut Url) -> Result<(), serde_qs::Error>,
+    ) -> Result<Response<D>, BinanceError<E>>
+    where
+        D: for<'de> Deserialize<'de>,
+        E: for<'de> Deserialize<'de>,
+    {
+        self.send_signed_with_body(Method::DELETE, path, weight, opts, body_capacity, add_params).await
+    }
+
+    /// Sends the params, timestamp and signature in a form-encoded body,
+    /// which keeps long payloads out of the URL and of proxy access logs.
+    #[inline]
+    async fn send_signed_with_body<D, E>(
+        &self,
+        method: Method,
+        path: &str,
+        weight: u32,
+        opts: &RequestOptions,
+        body_capacity: usize,
+        add_params: impl FnOnce(&mut Url) -> Result<(), serde_qs::Error>,
+    ) -> Result<Response<D>, BinanceError<E>>
+    where
+        D: for<'de> Deserialize<'de>,
+        E: for<'de> Deserialize<'de>,
+    {
+        let url_capacity = self.host.len() + path.len() + 1 + body_capacity + self.signed_params_len();
         let mut url = Url::with_capacity(&self.host, path, url_capacity);
+        url.start_body();
         add_params(&mut url)?;
-        self.add_signed_params(&mut url);
 
-        #[cfg(debug_assertions)]
-        debug_url("final", &url);
+        self.send(method, url, weight, Security::Signed, opts).await
+    }
+
+    #[inline]
+    fn signed_params_len(&self) -> usize {
+        SIGNED_PARAMS_LEN + self.signer.signature_len()
+    }
+
+    pub fn add_signed_params(&self, url: &mut Url) -> Result<(), SignerError> {
+        self.add_signed_params_with(url, &RequestOptions::default())
+    }
+
+    fn add_signed_params_with(&self, url: &mut Url, opts: &RequestOptions) -> Result<(), SignerError> {
+        match opts.recv_window {
+            Some(recv_window) => url.add_recv_window(recv_window),
+            None if self.recv_window != RECV_WINDOW_MS_DEFAULT => url.add_recv_window(self.recv_window),
+            None => {}
+        }
+        url.add_timestamp(self.timestamp());
+        match opts.credentials.as_ref() {
+            Some((_, signer)) => url.gen_and_add_signature(signer.as_ref()),
+            None => url.gen_and_add_signature(self.signer.as_ref()),
+        }
+    }
+
+    /// `timestamp` of signed requests: the clock corrected by the server time offset, in the time unit of the client.
+    fn timestamp(&self) -> i64 {
+        match self.time_unit {
+            TimeUnit::Millisecond => self.clock.now_millis() + self.time_sync.offset_ms(),
+            TimeUnit::Microsecond => self.clock.now_micros() + self.time_sync.offset_ms() * 1000,
+        }
+    }
+
+    #[inline]
+    fn signed_url(&self, url: &Url, opts: &RequestOptions) -> Result<Url, SignerError> {
+        let mut signed = url.clone();
+        self.add_signed_params_with(&mut signed, opts)?;
+        Ok(signed)
+    }
+
+    /// Measures the offset between the local and the server clock
+    /// and applies it to the timestamp of every following signed request.
+    pub async fn sync_server_time<E>(&self, path: &str) -> Result<TimeOffset, BinanceError<E>>
+    where
+        E: for<'de> Deserialize<'de>,
+    {
+        let sent_ms = self.clock.now_millis();
+        let resp: Response<ServerTime> = self.get(path, 1, &RequestOptions::default()).await?;
+        let received_ms = self.clock.now_millis();
+
+        Ok(self.time_sync.update(sent_ms, resp.payload.server_time.as_millis() as i64, received_ms))
+    }
+
+    /// Resolution of the response timestamps and of the `timestamp` param of signed requests.
+    #[inline]
+    pub fn time_unit(&self) -> TimeUnit {
+        self.time_unit
+    }
+
+    #[inline]
+    pub fn time_offset(&self) -> TimeOffset {
+        self.time_sync.offset()
+    }
+
+    /// Current client side weight accounting, if the rate limiter is enabled
+    /// and requests are not sent from several local addresses.
+    #[inline]
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_deref()
+    }
+
+    /// Order count of the account, if the order rate limiter is enabled.
+    #[inline]
+    pub fn order_rate_limiter(&self) -> Option<&RateLimiter> {
+        self.order_limiter.as_deref()
+    }
+
+    /// Local addresses of the client and their weight accounting, if several are bound.
+    #[inline]
+    pub fn source_ip_pool(&self) -> Option<&SourceIpPool> {
+        self.source_ips.as_deref()
+    }
+
+    /// Proxy of the client, WebSocket connections go through it as well.
+    #[inline]
+    pub fn proxy(&self) -> Option<&ProxyConfig> {
+        self.proxy.as_deref()
+    }
+
+    /// Replaces the weight limits of the rate limiter and of every local address
+    /// with the `REQUEST_WEIGHT` entries of `exchangeInfo`, and the order limits with the `ORDERS` ones.
+    pub fn set_exchange_limits(&self, rate_limits: &[RateLimit]) {
+        if let Some(limiter) = self.rate_limiter.as_ref() {
+            limiter.set_exchange_limits(rate_limits);
+        }
+        if let Some(limiter) = self.order_limiter.as_ref() {
+            limiter.set_exchange_limits(rate_limits);
+        }
+        if let Some(pool) = self.source_ips.as_ref() {
+            for source in pool.sources() {
+                source.limiter.set_exchange_limits(rate_limits);
+            }
+        }
+    }
+
+    #[inline]
+    fn limiter_for<'a>(&'a self, source: Option<&'a SourceIp>) -> Option<&'a RateLimiter> {
+        match source {
+            Some(source) => Some(&source.limiter),
+            None => self.rate_limiter.as_deref(),
+        }
+    }
+
+    /// Alternate hosts of the client, if configured.
+    #[inline]
+    pub fn host_pool(&self) -> Option<&HostPool> {
+        self.hosts.as_deref()
+    }
+
+    /// Pings every host of the pool concurrently and updates its health and latency.
+    ///
+    /// Health checks go straight to the transport, without interceptors, retries or metrics.
+    pub async fn check_hosts(&self, path: &str) {
+        let pool = match self.hosts.as_ref() {
+            Some(pool) => pool,
+            None => return,
+        };
+
+        let checks: Vec<_> = (0..pool.len())
+            .map(|idx| {
+                let client = self.clone();
+                let path = path.to_owned();
+                tokio::spawn(async move { client.ping_host(idx, &path).await })
+            })
+            .collect();
+        for (idx, check) in checks.into_iter().enumerate() {
+            pool.record_ping(idx, check.await.ok().flatten());
+        }
+    }
+
+    /// Opens up to `connections` pooled connections to the current host, with concurrent pings.
+    /// Returns how many pings succeeded.
+    ///
+    /// With several local addresses every one gets `connections`. The pings go straight to
+    /// the transport like the health checks, their weight is resynced by the next response.
+    pub async fn warm_up(&self, path: &str, connections: usize) -> usize {
+        let host = match self.hosts.as_ref() {
+            Some(pool) => pool.host(pool.select(None)).clone(),
+            None => self.host.clone(),
+        };
+        let url = format!("{}{}", host, path);
+        let transports: Vec<_> = match self.source_ips.as_ref() {
+            Some(pool) => pool.sources().iter().map(|source| source.transport.clone()).collect(),
+            None => vec![self.transport.clone()],
+        };
+
+        let pings: Vec<_> = transports
+            .iter()
+            .flat_map(|transport| (0..connections).map(move |_| transport.clone()))
+            .map(|transport| {
+                let url = url.clone();
+                tokio::spawn(async move { ping(transport.as_ref(), &url).await.is_some() })
+            })
+            .collect();
+        let mut succeeded = 0;
+        for ping in pings {
+            if ping.await.unwrap_or(false) {
+                succeeded += 1;
+            }
+        }
+        succeeded
+    }
+
+    async fn ping_host(&self, idx: usize, path: &str) -> Option<Duration> {
+        let host = self.hosts.as_ref()?.host(idx);
+        let url = format!("{}{}", host, path);
+        ping(self.transport.as_ref(), &url).await
+    }
 
-        self.signed_request(Method::DELETE, url).await
+    /// Counts `orders` new orders against the order limits of the account, before they are placed.
+    pub async fn acquire_orders<E>(&self, orders: u32) -> Result<(), BinanceError<E>> {
+        self.acquire_orders_with(orders, &RequestOptions::default()).await
     }
 
-    pub fn add_signed_params(&self, url: &mut Url) {
-        if self.recv_window != RECV_WINDOW_MS_DEFAULT {
-            url.add_recv_window(self.recv_window);
+    /// [`acquire_orders`](Self::acquire_orders) for the account the call is signed for.
+    pub async fn acquire_orders_with<E>(&self, orders: u32, opts: &RequestOptions) -> Result<(), BinanceError<E>> {
+        if let Some(limiter) = self.order_limiter_for(opts) {
+            limiter.acquire(orders).await.map_err(BinanceError::RateLimited)?;
+        }
+        Ok(())
+    }
+
+    /// The order limiter of the account whose credentials sign the call.
+    #[inline]
+    fn order_limiter_for<'a>(&'a self, opts: &'a RequestOptions) -> Option<&'a RateLimiter> {
+        match opts.credentials {
+            Some(_) => opts.order_limiter.as_deref(),
+            None => self.order_limiter.as_deref(),
+        }
+    }
+
+    /// The same client signing with the credentials of another account.
+    ///
+    /// Connections, weight limits and local addresses are shared, they are per IP address.
+    /// The order limits are per account, so the order limiter is replaced.
+    pub(crate) fn with_account(
+        &self,
+        api_key: ApiKey,
+        signer: Arc<dyn Signer>,
+        order_limiter: Option<Arc<RateLimiter>>,
+    ) -> BinanceClient {
+        BinanceClient {
+            api_key: Some(api_key),
+            signer,
+            order_limiter,
+            ..self.clone()
         }
-        url.add_timestamp();
-        url.gen_and_add_signature(&self.secret_key);
     }
 
     #[inline(always)]
-    async fn request<D, E>(
+    async fn acquire_weight<E>(&self, weight: u32, source: Option<&SourceIp>) -> Result<(), BinanceError<E>> {
+        match (source, self.source_ips.as_ref()) {
+            // only counted to pick the address
+            (Some(source), Some(pool)) if !pool.enforce() => source.limiter.reserve(weight),
+            _ => {
+                if let Some(limiter) = self.limiter_for(source) {
+                    limiter.acquire(weight).await.map_err(BinanceError::RateLimited)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn send<D, E>(
         &self,
         method: Method,
         url: Url,
+        weight: u32,
+        security: Security,
+        opts: &RequestOptions,
     ) -> Result<Response<D>, BinanceError<E>>
     where
         D: for<'de> Deserialize<'de>,
         E: for<'de> Deserialize<'de>,
     {
-        let resp = self.client.request(method, url.as_str()).send().await?;
-        BinanceClient::handle_response(resp).await
+        #[cfg(feature = "tracing")]
+        let span = trace::request_span(&method, url.path());
+
+        let result = async {
+            let received = self.send_attempts(method, url, weight, security, opts).await?;
+            self.decode_response(received).await
+        };
+        #[cfg(feature = "tracing")]
+        let result = trace::instrument(result, span);
+        result.await
     }
 
-    #[inline(always)]
-    async fn signed_request<D, E>(
+    /// Like `send`, but the body of a successful response is returned as is.
+    async fn send_raw<E>(
         &self,
         method: Method,
         url: Url,
-    ) -> Result<Response<D>, BinanceError<E>>
+        weight: u32,
+        security: Security,
+        opts: &RequestOptions,
+    ) -> Result<Response<Bytes>, BinanceError<E>>
     where
-        D: for<'de> Deserialize<'de>,
         E: for<'de> Deserialize<'de>,
     {
-        let resp = self
-            .client
-            .request(method, url.as_str())
-            .header(
-                HeaderName::from_static("x-mbx-apikey"),
-                HeaderValue::from_str(&self.api_key).unwrap(),
-            )
-            .send()
-            .await?;
-        BinanceClient::handle_response(resp).await
-    }
-
-    async fn handle_response<D, E>(resp: reqwest::Response) -> Result<Response<D>, BinanceError<E>>
+        #[cfg(feature = "tracing")]
+        let span = trace::request_span(&method, url.path());
+
+        let result = async {
+            let received = self.send_attempts(method, url, weight, security, opts).await?;
+            self.raw_response(received).await
+        };
+        #[cfg(feature = "tracing")]
+        let result = trace::instrument(result, span);
+        result.await
+    }
+
+    async fn send_attempts<E>(
+        &self,
+        method: Method,
+        url: Url,
+        weight: u32,
+        security: Security,
+        opts: &RequestOptions,
+    ) -> Result<Received, BinanceError<E>> {
+        let mut attempt = 1;
+        let mut failovers = 0;
+        let mut failed_host = None;
+        loop {
+            let source = self.source_ips.as_ref().map(|pool| pool.select());
+            self.acquire_weight(weight, source).await?;
+
+            let host_idx = self.hosts.as_ref().map(|pool| pool.select(failed_host));
+            let host = match (self.hosts.as_ref(), host_idx) {
+                (Some(pool), Some(idx)) => pool.host(idx).clone(),
+                _ => self.host.clone(),
+            };
+            let routed_url;
+            let host_url = if *host == *url.host() {
+                &url
+            } else {
+                routed_url = url.with_host(&host);
+                &routed_url
+            };
+
+            // Signed requests get a fresh timestamp and signature on every attempt.
+            let signed_url;
+            let request_url = match security {
+                Security::Signed => {
+                    signed_url = self.signed_url(host_url, opts)?;
+                    &signed_url
+                }
+                Security::None | Security::ApiKey => host_url,
+            };
+
+            // The extra headers of the call can't set the reserved ones.
+            let mut headers = opts.headers.clone();
+            headers.remove(HeaderName::from_static("x-mbx-apikey"));
+            headers.remove(HeaderName::from_static("x-mbx-time-unit"));
+            if security != Security::None {
+                let api_key = opts.credentials.as_ref().map(|(api_key, _)| api_key).or(self.api_key.as_ref());
+                if let Some(api_key) = api_key {
+                    headers.insert(HeaderName::from_static("x-mbx-apikey"), api_key.header_value()?);
+                }
+            }
+            if self.time_unit == TimeUnit::Microsecond {
+                headers.insert(
+                    HeaderName::from_static("x-mbx-time-unit"),
+                    HeaderValue::from_static(TimeUnit::Microsecond.as_str()),
+                );
+            }
+
+            let body = request_url.body().map(str::as_bytes);
+            if body.is_some() {
+                headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/x-www-form-urlencoded"));
+            }
+
+            let mut parts = RequestParts {
+                method: &method,
+                url: request_url.request_url(),
+                body,
+                path: url.path(),
+                attempt,
+                weight,
+                headers: &mut headers,
+            };
+            for interceptor in self.interceptors.iter() {
+                interceptor.before_request(&mut parts);
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(attempt, url = %trace::RedactedUrl(parts.url), "sending request");
+
+            let started = Instant::now();
+            let transport = source.map_or(&self.transport, |source| &source.transport);
+            let outcome = transport
+                .send(TransportRequest {
+                    method: &method,
+                    url: parts.url,
+                    headers: parts.headers,
+                    body,
+                    timeout: opts.timeout,
+                })
+                .await;
+
+            let elapsed = started.elapsed();
+            if !self.interceptors.is_empty() {
+                let meta = outcome.as_ref().map(|resp| ResponseMeta {
+                    status: resp.status,
+                    headers: &resp.headers,
+                    body_len: resp.body.len(),
+                    elapsed,
+                });
+                for interceptor in self.interceptors.iter() {
+                    interceptor.after_response(&parts, meta.as_ref().map_err(|err| *err));
+                }
+            }
+            if let (Some(pool), Some(idx)) = (self.hosts.as_ref(), host_idx) {
+                let failed = outcome.as_ref().map_or(true, |resp| resp.status.is_server_error());
+                if failed {
+                    pool.mark_unhealthy(idx);
+                    failed_host = Some(idx);
+                } else {
+                    pool.mark_healthy(idx);
+                    failed_host = None;
+                }
+            }
+
+            let (reason, retry_after) = match outcome {
+                Ok(ref resp) => (
+                    RetryReason::Status(resp.status),
+                    response::retry_after(&resp.headers),
+                ),
+                Err(ref err) if err.kind() == TransportErrorKind::Connect => (RetryReason::Connect, None),
+                Err(_) => (RetryReason::Transport, None),
+            };
+
+            let delay = self
+                .retry_policy
+                .retry_delay(&method, attempt, reason, retry_after)
+                .or_else(|| self.failover_delay(&method, reason, &mut failovers));
+            let delay = match delay {
+                Some(delay) => delay,
+                None => {
+                    #[cfg(feature = "tracing")]
+                    {
+                        tracing::Span::current().record("attempts", attempt);
+                        if let Err(ref err) = outcome {
+                            tracing::debug!(attempt, error = %err, "request failed");
+                        }
+                    }
+                    return match outcome {
+                        Ok(resp) => {
+                            let limiters = [self.limiter_for(source), self.order_limiter_for(opts)];
+                            Ok(self.handle_response(resp, host, limiters, &method, url.path(), elapsed))
+                        }
+                        Err(err) => {
+                            self.record_metrics(RequestMetrics {
+                                host: &host,
+                                method: &method,
+                                path: url.path(),
+                                status: None,
+                                latency: elapsed,
+                                used_weight_1m: None,
+                                error_code: None,
+                            });
+                            Err(err.into())
+                        }
+                    };
+                }
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(attempt, ?reason, delay_ms = delay.as_millis() as u64, "retrying request");
+
+            if let Ok(resp) = outcome.as_ref() {
+                let headers = BinanceHttpHeader::from(&resp.headers);
+                for limiter in [self.limiter_for(source), self.order_limiter_for(opts)].into_iter().flatten() {
+                    limiter.update(resp.status, &headers);
+                }
+            }
+            self.retry_policy.notify(&RetryEvent {
+                method: &method,
+                path: url.path(),
+                attempt,
+                reason,
+                delay,
+            });
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// No delay if the failed attempt may be sent again to another host:
+    /// it never reached the server, or it is idempotent and got a 5xx.
+    fn failover_delay(&self, method: &Method, reason: RetryReason, failovers: &mut usize) -> Option<Duration> {
+        let pool = self.hosts.as_ref()?;
+        let safe = match reason {
+            RetryReason::Connect => true,
+            RetryReason::Status(status) => status.is_server_error() && is_idempotent(method),
+            RetryReason::Transport => false,
+        };
+        if !safe || *failovers + 1 >= pool.len() {
+            return None;
+        }
+
+        *failovers += 1;
+        Some(Duration::ZERO)
+    }
+
+    #[inline]
+    fn record_metrics(&self, metrics: RequestMetrics) {
+        if let Some(sink) = self.metrics.as_ref() {
+            sink.record(&metrics);
+        }
+    }
+
+    /// Accounts the final response to the rate limiters, the metrics and the trace span.
+    fn handle_response(
+        &self,
+        resp: TransportResponse,
+        host: Arc<str>,
+        limiters: [Option<&RateLimiter>; 2],
+        method: &Method,
+        path: &str,
+        latency: Duration,
+    ) -> Received {
+        let status = resp.status;
+        let headers = BinanceHttpHeader::from(&resp.headers);
+        for limiter in limiters.into_iter().flatten() {
+            limiter.update(status, &headers);
+        }
+        if self.metrics.is_some() {
+            let error_code = if status.is_success() {
+                None
+            } else {
+                response::peek_error_code(resp.body.as_ref())
+            };
+            self.record_metrics(RequestMetrics {
+                host: &host,
+                method,
+                path,
+                status: Some(status),
+                latency,
+                used_weight_1m: headers.x_mbx_used_weight_1m,
+                error_code,
+            });
+        }
+
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            span.record("host", &*host);
+            span.record("status", status.as_u16());
+            if let Some(used_weight) = headers.x_mbx_used_weight_1m {
+                span.record("used_weight", used_weight);
+            }
+            if !status.is_success() {
+                if let Some(code) = response::peek_error_code(resp.body.as_ref()) {
+                    span.record("error_code", code);
+                }
+            }
+        }
+
+        Received { resp, host, headers }
+    }
+
+    /// The body to keep on the response, if enabled.
+    #[inline]
+    fn raw_body(&self, body: &Bytes) -> Option<Bytes> {
+        if !self.keep_raw_body {
+            return None;
+        }
+        Some(body.clone())
+    }
+
+    #[inline]
+    fn decode<D>(&self, body: &[u8]) -> Result<D, json::JsonError>
+    where
+        D: for<'de> Deserialize<'de>,
+    {
+        self.json.decode_in(body, self.time_unit)
+    }
+
+    async fn decode_response<D, E>(&self, received: Received) -> Result<Response<D>, BinanceError<E>>
     where
         D: for<'de> Deserialize<'de>,
         E: for<'de> Deserialize<'de>,
     {
-        let status = resp.status();
-        let headers: BinanceHttpHeader = resp.headers().try_into()?;
-        let json_bytes = resp.bytes().await?;
-        let json: response::SuccessOrError<D, E> = json::from_slice(json_bytes.as_ref()).await?;
+        let Received { resp, host, headers } = received;
+        let raw = self.raw_body(&resp.body);
+        // chosen by the status, an untagged enum would buffer the body and lose the time unit,
+        // and a permissive payload like `Pong` would accept an error body
+        let json: response::SuccessOrError<D, E> = if resp.status.is_success() {
+            response::SuccessOrError::Success(self.decode(&resp.body)?)
+        } else {
+            response::SuccessOrError::Error(self.decode(&resp.body)?)
+        };
 
-        json.to_result(status, headers)
+        json.to_result(host, resp.status, headers, raw, self.time_unit)
+    }
+
+    async fn raw_response<E>(&self, received: Received) -> Result<Response<Bytes>, BinanceError<E>>
+    where
+        E: for<'de> Deserialize<'de>,
+    {
+        let Received { resp, host, headers } = received;
+        let raw = self.raw_body(&resp.body);
+        if resp.status.is_success() {
+            return Ok(Response {
+                host,
+                headers,
+                status: resp.status,
+                payload: resp.body,
+                raw,
+                time_unit: self.time_unit,
+            });
+        }
+
+        let payload: E = self.decode(&resp.body)?;
+        Err(BinanceError::Binance(Response {
+            host,
+            headers,
+            status: resp.status,
+            payload,
+            raw,
+            time_unit: self.time_unit,
+        }))
     }
 }
 
-fn debug_url(marker: &str, url: &Url) {
-    println!(
-        "{} url: {}\nlen: {}\ncapacity: {}",
-        marker,
-        url.as_str(),
-        url.len(),
-        url.capacity(),
-    );
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use super::*;
+    use crate::api::client::clock::FixedClock;
+    use crate::schemes::common::Pong;
+    use crate::schemes::error::Error;
+
+    const API_KEY: &str = "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A";
+    const SECRET_KEY: &str = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j";
+
+    fn signed_client(host: String) -> BinanceClient {
+        let mut builder = BinanceClient::builder(host);
+        builder.api_key = Some(API_KEY.into());
+        builder.signing_key = Some(SigningKey::Hmac(SECRET_KEY.into()));
+        builder.clock = Some(Arc::new(FixedClock::from_millis(1499827319559)));
+        builder.build().unwrap()
+    }
+
+    /// The form-encoded body expected for `params`, signed over all of it as there is no query.
+    fn signed_body(params: &str) -> String {
+        let payload = format!("{}&timestamp=1499827319559", params);
+        let mut signature = String::new();
+        HmacSigner::new(SECRET_KEY.as_bytes()).sign(payload.as_bytes(), &mut signature).unwrap();
+        format!("{}&signature={}", payload, signature)
+    }
+
+    async fn assert_signed_with_body(method: Method) {
+        let server = MockServer::start_async().await;
+        let body = signed_body("symbol=BTCUSDT&orderId=42");
+
+        let mock_method = match method {
+            Method::PUT => PUT,
+            _ => DELETE,
+        };
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(mock_method)
+                    .path("/api/v3/order")
+                    .header("x-mbx-apikey", API_KEY)
+                    .header("content-type", "application/x-www-form-urlencoded")
+                    .body(&body)
+                    .matches(|req| req.query_params.as_ref().is_none_or(|params| params.is_empty()));
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body("{}");
+            })
+            .await;
+
+        let client = signed_client(server.url(""));
+        let opts = RequestOptions::default();
+        let add_params = |url: &mut Url| {
+            url.add_param_str("symbol", "BTCUSDT");
+            url.add_param_integer("orderId", 42);
+            Ok(())
+        };
+        let response: Result<Response<serde::de::IgnoredAny>, BinanceError<Error>> = match method {
+            Method::PUT => client.put_signed_with_body("/api/v3/order", 1, &opts, 64, add_params).await,
+            _ => client.delete_signed_with_body("/api/v3/order", 1, &opts, 64, add_params).await,
+        };
+        assert!(response.is_ok());
+
+        binance_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_request_headers_do_not_override_reserved_headers() {
+        const OTHER_API_KEY: &str = "yyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy";
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/api/v3/openOrders")
+                    .header("x-request-tag", "latency-critical")
+                    .matches(|req| {
+                        let values = |name: &str| {
+                            req.headers
+                                .iter()
+                                .flatten()
+                                .filter(|(k, _)| k.eq_ignore_ascii_case(name))
+                                .map(|(_, v)| v.as_str())
+                                .collect::<Vec<_>>()
+                        };
+                        values("x-mbx-apikey") == [API_KEY] && values("x-mbx-time-unit").is_empty()
+                    });
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body("[]");
+            })
+            .await;
+
+        let client = signed_client(server.url(""));
+        let opts = RequestOptions::new()
+            .header(HeaderName::from_static("x-mbx-apikey"), HeaderValue::from_static(OTHER_API_KEY))
+            .header(HeaderName::from_static("x-mbx-time-unit"), HeaderValue::from_static("MICROSECOND"))
+            .header(HeaderName::from_static("x-request-tag"), HeaderValue::from_static("latency-critical"));
+        let response: Result<Response<serde::de::IgnoredAny>, BinanceError<Error>> = client
+            .get_signed_with_query("/api/v3/openOrders", 3, &opts, 32, |_| Ok(()))
+            .await;
+        assert!(response.is_ok());
+
+        binance_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_error_status_is_not_decoded_as_payload() {
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/api/v3/ping");
+
+                then.status(400)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body(r#"{"code":-1121,"msg":"Invalid symbol."}"#);
+            })
+            .await;
+
+        let client = BinanceClient::builder(server.url("")).build().unwrap();
+        let response: Result<Response<Pong>, BinanceError<Error>> =
+            client.get("/api/v3/ping", 1, &RequestOptions::default()).await;
+        match response {
+            Err(BinanceError::Binance(resp)) => assert_eq!(resp.payload.code, -1121),
+            other => panic!("expected a binance error, got {:?}", other.map(|resp| resp.status)),
+        }
+
+        binance_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_put_signed_with_body() {
+        assert_signed_with_body(Method::PUT).await;
+    }
+
+    #[tokio::test]
+    async fn test_delete_signed_with_body() {
+        assert_signed_with_body(Method::DELETE).await;
+    }
 }
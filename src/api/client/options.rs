@@ -0,0 +1,82 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use super::credentials::ApiKey;
+use super::rate_limit::RateLimiter;
+use super::signer::Signer;
+
+/// Overrides of the client settings for a single call.
+///
+/// # Examples
+///
+/// ```ignore
+/// use std::time::Duration;
+/// use binancex::RequestOptions;
+///
+/// let opts = RequestOptions::new()
+///     .recv_window(500)
+///     .timeout(Duration::from_millis(300));
+/// let order = client.new_order_with(&req, &opts).await?;
+/// ```
+#[derive(Clone, Default)]
+pub struct RequestOptions {
+    pub(crate) recv_window: Option<u16>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) headers: HeaderMap,
+    pub(crate) credentials: Option<(ApiKey, Arc<dyn Signer>)>,
+    pub(crate) order_limiter: Option<Arc<RateLimiter>>,
+}
+
+impl RequestOptions {
+    pub fn new() -> Self {
+        RequestOptions::default()
+    }
+
+    /// `recvWindow` of signed requests, in milliseconds.
+    pub fn recv_window(mut self, recv_window: u16) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    /// Timeout of each attempt, from sending the request to receiving the whole body.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Extra header, added before the interceptors run. `x-mbx-apikey` and `x-mbx-time-unit`
+    /// are skipped: set the API key with [`credentials`](Self::credentials).
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.append(name, value);
+        self
+    }
+
+    /// Sends the call with another API key and signer.
+    ///
+    /// Orders are then not counted against the order limiter of the client, which belongs to
+    /// its own account, but against the `order_limiter` of these options if one is set.
+    pub fn credentials(mut self, api_key: ApiKey, signer: Arc<dyn Signer>) -> Self {
+        self.credentials = Some((api_key, signer));
+        self
+    }
+
+    /// Order count of the account of `credentials`, see `RateLimiter::orders`.
+    pub fn order_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.order_limiter = Some(limiter);
+        self
+    }
+}
+
+impl std::fmt::Debug for RequestOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RequestOptions")
+            .field("recv_window", &self.recv_window)
+            .field("timeout", &self.timeout)
+            .field("headers", &self.headers)
+            .field("credentials", &self.credentials.as_ref().map(|_| "<redacted>"))
+            .field("order_limiter", &self.order_limiter)
+            .finish()
+    }
+}
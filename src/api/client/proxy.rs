@@ -0,0 +1,68 @@
+/// Proxy every connection of the client is made through, REST requests as well as WebSocket streams.
+///
+/// The scheme of the URL picks the proxy protocol: `http://` and `https://` tunnel
+/// through `CONNECT`, `socks5://` and `socks5h://` (the proxy resolves the host)
+/// need the `socks` feature.
+///
+/// # Examples
+///
+/// ```ignore
+/// use binancex::{BinanceSpot, ProxyConfig};
+///
+/// let client = BinanceSpot::builder()
+///     .proxy(ProxyConfig::new("http://proxy.corp:3128").basic_auth("user", "pass"))
+///     .build()?;
+/// ```
+#[derive(Clone, Eq, PartialEq)]
+pub struct ProxyConfig {
+    url: String,
+    basic_auth: Option<(String, String)>,
+}
+
+impl ProxyConfig {
+    pub fn new(url: impl Into<String>) -> Self {
+        ProxyConfig {
+            url: url.into(),
+            basic_auth: None,
+        }
+    }
+
+    /// Credentials sent in `Proxy-Authorization` to HTTP proxies and in the handshake to SOCKS5 ones.
+    pub fn basic_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.basic_auth = Some((username.into(), password.into()));
+        self
+    }
+
+    #[inline]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Username and password, if any.
+    #[inline]
+    pub fn credentials(&self) -> Option<(&str, &str)> {
+        self.basic_auth
+            .as_ref()
+            .map(|(username, password)| (username.as_str(), password.as_str()))
+    }
+
+    pub(crate) fn to_reqwest(&self) -> reqwest::Result<reqwest::Proxy> {
+        let mut proxy = reqwest::Proxy::all(&self.url)?;
+        if let Some((username, password)) = self.credentials() {
+            proxy = proxy.basic_auth(username, password);
+        }
+        Ok(proxy)
+    }
+}
+
+impl std::fmt::Debug for ProxyConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ProxyConfig")
+            .field("url", &self.url)
+            .field(
+                "basic_auth",
+                &self.basic_auth.as_ref().map(|(username, _)| (username, "<redacted>")),
+            )
+            .finish()
+    }
+}
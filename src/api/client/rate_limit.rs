@@ -0,0 +1,415 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::api::client::response::BinanceHttpHeader;
+use crate::schemes::common::general::{RateLimit, RateLimitInterval, RateLimitType};
+
+use reqwest::StatusCode;
+
+/// What the client does when a request would exceed the weight budget.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RateLimitMode {
+    /// Sleep until the current interval ends.
+    Wait,
+    /// Return `BinanceError::RateLimited` without sending the request.
+    FailFast,
+}
+
+/// What a limiter counts.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LimitKind {
+    /// `REQUEST_WEIGHT`, counted per IP address.
+    RequestWeight,
+    /// `ORDERS`, counted per account.
+    Orders,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct WeightLimit {
+    pub interval_ms: u64,
+    pub limit: u32,
+}
+
+impl WeightLimit {
+    pub fn per_minute(limit: u32) -> Self {
+        WeightLimit {
+            interval_ms: 60_000,
+            limit,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct WeightWindow {
+    limit: WeightLimit,
+    window_id: u64,
+    used: u32,
+}
+
+impl WeightWindow {
+    #[inline]
+    fn roll(&mut self, now_ms: u64) {
+        let window_id = now_ms / self.limit.interval_ms;
+        if window_id != self.window_id {
+            self.window_id = window_id;
+            self.used = 0;
+        }
+    }
+
+    #[inline]
+    fn fits(&self, weight: u32) -> bool {
+        // A request heavier than the whole budget is let through on an empty window.
+        self.used == 0 || self.used.saturating_add(weight) <= self.limit.limit
+    }
+
+    #[inline]
+    fn ms_until_reset(&self, now_ms: u64) -> u64 {
+        (self.window_id + 1) * self.limit.interval_ms - now_ms
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    windows: Vec<WeightWindow>,
+    banned_until_ms: u64,
+}
+
+/// Client side request weight accounting.
+///
+/// Weight is reserved before a request is sent and resynced from the
+/// `x-mbx-used-weight-<interval>` headers of every response.
+/// An order limiter counts placed orders the same way, from the `x-mbx-order-count-<interval>` headers.
+#[derive(Debug)]
+pub struct RateLimiter {
+    mode: RateLimitMode,
+    kind: LimitKind,
+    state: Mutex<State>,
+}
+
+impl RateLimiter {
+    pub fn new(mode: RateLimitMode, limits: &[WeightLimit]) -> Self {
+        RateLimiter::with_kind(mode, LimitKind::RequestWeight, limits)
+    }
+
+    /// Counts the orders of an account, a weight of 1 per order.
+    pub fn orders(mode: RateLimitMode, limits: &[WeightLimit]) -> Self {
+        RateLimiter::with_kind(mode, LimitKind::Orders, limits)
+    }
+
+    fn with_kind(mode: RateLimitMode, kind: LimitKind, limits: &[WeightLimit]) -> Self {
+        let limiter = RateLimiter {
+            mode,
+            kind,
+            state: Mutex::new(State::default()),
+        };
+        limiter.set_limits(limits);
+        limiter
+    }
+
+    #[inline]
+    pub fn mode(&self) -> RateLimitMode {
+        self.mode
+    }
+
+    #[inline]
+    pub fn kind(&self) -> LimitKind {
+        self.kind
+    }
+
+    pub fn set_limits(&self, limits: &[WeightLimit]) {
+        let mut state = self.state.lock().unwrap();
+        let windows = limits
+            .iter()
+            .map(|limit| {
+                let used = state
+                    .windows
+                    .iter()
+                    .find(|w| w.limit.interval_ms == limit.interval_ms)
+                    .map(|w| (w.window_id, w.used))
+                    .unwrap_or((0, 0));
+                WeightWindow {
+                    limit: *limit,
+                    window_id: used.0,
+                    used: used.1,
+                }
+            })
+            .collect();
+        state.windows = windows;
+    }
+
+    /// Replaces the limits with the `REQUEST_WEIGHT` (or `ORDERS`) entries of `exchangeInfo`.
+    pub fn set_exchange_limits(&self, rate_limits: &[RateLimit]) {
+        let limits: Vec<WeightLimit> = rate_limits
+            .iter()
+            .filter(|l| match self.kind {
+                LimitKind::RequestWeight => matches!(l.rate_limit_type, RateLimitType::RequestWeight),
+                LimitKind::Orders => matches!(l.rate_limit_type, RateLimitType::Orders),
+            })
+            .map(|l| WeightLimit {
+                interval_ms: interval_to_ms(&l.interval) * l.interval_num as u64,
+                limit: l.limit,
+            })
+            .collect();
+
+        if !limits.is_empty() {
+            self.set_limits(&limits);
+        }
+    }
+
+    pub fn limits(&self) -> Vec<WeightLimit> {
+        let state = self.state.lock().unwrap();
+        state.windows.iter().map(|w| w.limit).collect()
+    }
+
+    /// Weight used in the current window of the interval, if such a limit is tracked.
+    pub fn used_weight(&self, interval_ms: u64) -> Option<u32> {
+        let now_ms = now_ms();
+        let mut state = self.state.lock().unwrap();
+        state
+            .windows
+            .iter_mut()
+            .find(|w| w.limit.interval_ms == interval_ms)
+            .map(|w| {
+                w.roll(now_ms);
+                w.used
+            })
+    }
+
+    /// Reserves `weight` in every window or returns how long to wait until it fits.
+    pub(crate) fn try_acquire_at(&self, weight: u32, now_ms: u64) -> Result<(), Duration> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.banned_until_ms > now_ms {
+            return Err(Duration::from_millis(state.banned_until_ms - now_ms));
+        }
+
+        let mut wait_ms = 0;
+        for window in state.windows.iter_mut() {
+            window.roll(now_ms);
+            if !window.fits(weight) {
+                wait_ms = wait_ms.max(window.ms_until_reset(now_ms));
+            }
+        }
+        if wait_ms > 0 {
+            return Err(Duration::from_millis(wait_ms));
+        }
+
+        for window in state.windows.iter_mut() {
+            window.used += weight;
+        }
+        Ok(())
+    }
+
+    /// Free weight in the window closest to its limit, 0 while banned after a 429/418.
+    pub fn headroom(&self) -> u32 {
+        self.headroom_at(now_ms())
+    }
+
+    pub(crate) fn headroom_at(&self, now_ms: u64) -> u32 {
+        let mut state = self.state.lock().unwrap();
+        if state.banned_until_ms > now_ms {
+            return 0;
+        }
+
+        state
+            .windows
+            .iter_mut()
+            .map(|window| {
+                window.roll(now_ms);
+                window.limit.limit.saturating_sub(window.used)
+            })
+            .min()
+            .unwrap_or(u32::MAX)
+    }
+
+    /// Counts `weight` in every window without waiting, even past the limit.
+    pub(crate) fn reserve_at(&self, weight: u32, now_ms: u64) {
+        let mut state = self.state.lock().unwrap();
+        for window in state.windows.iter_mut() {
+            window.roll(now_ms);
+            window.used = window.used.saturating_add(weight);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn reserve(&self, weight: u32) {
+        self.reserve_at(weight, now_ms());
+    }
+
+    /// Waits for (or, in `FailFast` mode, fails with the time until) enough free weight.
+    pub async fn acquire(&self, weight: u32) -> Result<(), Duration> {
+        loop {
+            match self.try_acquire_at(weight, now_ms()) {
+                Ok(()) => return Ok(()),
+                Err(wait) if self.mode == RateLimitMode::FailFast => return Err(wait),
+                Err(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+
+    pub(crate) fn update_at(&self, status: StatusCode, headers: &BinanceHttpHeader, now_ms: u64) {
+        let mut state = self.state.lock().unwrap();
+
+        let counts = match self.kind {
+            LimitKind::RequestWeight => &headers.used_weight,
+            LimitKind::Orders => &headers.order_count,
+        };
+        for window in state.windows.iter_mut() {
+            if let Some(used) = counts.get_ms(window.limit.interval_ms) {
+                window.roll(now_ms);
+                window.used = used;
+            }
+        }
+
+        // bans are per IP, the order count headers tell when an account is over its limit
+        if self.kind == LimitKind::RequestWeight
+            && (status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::IM_A_TEAPOT)
+        {
+            let retry_after_ms = headers.retry_after.map(|s| s as u64 * 1000).unwrap_or(0);
+            state.banned_until_ms = state.banned_until_ms.max(now_ms + retry_after_ms);
+            for window in state.windows.iter_mut() {
+                window.roll(now_ms);
+                window.used = window.used.max(window.limit.limit);
+            }
+        }
+    }
+
+    /// Resyncs the used weight from the headers of a response.
+    pub fn update(&self, status: StatusCode, headers: &BinanceHttpHeader) {
+        self.update_at(status, headers, now_ms());
+    }
+}
+
+#[inline]
+fn interval_to_ms(interval: &RateLimitInterval) -> u64 {
+    match interval {
+        RateLimitInterval::Second => 1_000,
+        RateLimitInterval::Minute => 60_000,
+        RateLimitInterval::Day => 86_400_000,
+    }
+}
+
+#[inline]
+fn now_ms() -> u64 {
+    chrono::Utc::now().timestamp_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::client::response::{HeaderInterval, IntervalUnit};
+
+    #[test]
+    fn test_rate_limiter_reserves_and_resyncs_weight() {
+        let limiter = RateLimiter::new(RateLimitMode::FailFast, &[WeightLimit::per_minute(10)]);
+        let now = 120_000;
+
+        assert!(limiter.try_acquire_at(6, now).is_ok());
+        assert_eq!(
+            limiter.try_acquire_at(5, now + 1_000),
+            Err(Duration::from_millis(59_000)),
+        );
+
+        let headers = BinanceHttpHeader {
+            x_mbx_used_weight_1m: Some(2),
+            used_weight: [(HeaderInterval::new(1, IntervalUnit::Minute), 2)].into_iter().collect(),
+            ..Default::default()
+        };
+        limiter.update_at(StatusCode::OK, &headers, now + 2_000);
+        assert!(limiter.try_acquire_at(5, now + 3_000).is_ok());
+
+        // next window starts empty
+        assert!(limiter.try_acquire_at(10, now + 60_000).is_ok());
+    }
+
+    #[test]
+    fn test_rate_limiter_rejects_weight_overflowing_the_budget() {
+        let limiter = RateLimiter::new(RateLimitMode::FailFast, &[WeightLimit::per_minute(10)]);
+
+        assert!(limiter.try_acquire_at(1, 0).is_ok());
+        assert_eq!(limiter.try_acquire_at(u32::MAX, 1_000), Err(Duration::from_millis(59_000)));
+    }
+
+    #[test]
+    fn test_rate_limiter_honors_retry_after() {
+        let limiter = RateLimiter::new(RateLimitMode::FailFast, &[WeightLimit::per_minute(10)]);
+        let headers = BinanceHttpHeader {
+            retry_after: Some(90),
+            ..Default::default()
+        };
+        limiter.update_at(StatusCode::TOO_MANY_REQUESTS, &headers, 0);
+
+        assert_eq!(limiter.try_acquire_at(1, 1_000), Err(Duration::from_millis(89_000)));
+        assert!(limiter.try_acquire_at(1, 90_000).is_ok());
+    }
+
+    #[test]
+    fn test_rate_limiter_resyncs_every_interval() {
+        let limiter = RateLimiter::new(
+            RateLimitMode::FailFast,
+            &[
+                WeightLimit { interval_ms: 10_000, limit: 5 },
+                WeightLimit::per_minute(100),
+            ],
+        );
+        let headers = BinanceHttpHeader {
+            used_weight: [
+                (HeaderInterval::new(10, IntervalUnit::Second), 5),
+                (HeaderInterval::new(1, IntervalUnit::Minute), 40),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        limiter.update_at(StatusCode::OK, &headers, 120_000);
+
+        assert_eq!(limiter.try_acquire_at(1, 121_000), Err(Duration::from_millis(9_000)));
+        assert!(limiter.try_acquire_at(1, 130_000).is_ok());
+        assert_eq!(limiter.try_acquire_at(60, 175_000), Err(Duration::from_millis(5_000)));
+    }
+
+    #[test]
+    fn test_rate_limiter_headroom() {
+        let limiter = RateLimiter::new(
+            RateLimitMode::Wait,
+            &[
+                WeightLimit { interval_ms: 10_000, limit: 50 },
+                WeightLimit::per_minute(100),
+            ],
+        );
+        assert_eq!(limiter.headroom_at(120_000), 50);
+
+        limiter.reserve_at(30, 120_000);
+        assert_eq!(limiter.headroom_at(121_000), 20);
+        limiter.reserve_at(30, 121_000);
+        assert_eq!(limiter.headroom_at(122_000), 0);
+
+        // the 10s window is empty again, the minute one is not
+        assert_eq!(limiter.headroom_at(130_000), 40);
+
+        let headers = BinanceHttpHeader {
+            retry_after: Some(5),
+            ..Default::default()
+        };
+        limiter.update_at(StatusCode::TOO_MANY_REQUESTS, &headers, 130_000);
+        assert_eq!(limiter.headroom_at(131_000), 0);
+    }
+
+    #[test]
+    fn test_order_limiter_resyncs_from_order_count() {
+        let limiter = RateLimiter::orders(RateLimitMode::FailFast, &[WeightLimit { interval_ms: 10_000, limit: 50 }]);
+        assert_eq!(limiter.kind(), LimitKind::Orders);
+
+        let headers = BinanceHttpHeader {
+            used_weight: [(HeaderInterval::new(10, IntervalUnit::Second), 1)].into_iter().collect(),
+            order_count: [(HeaderInterval::new(10, IntervalUnit::Second), 49)].into_iter().collect(),
+            retry_after: Some(30),
+            ..Default::default()
+        };
+        limiter.update_at(StatusCode::TOO_MANY_REQUESTS, &headers, 120_000);
+
+        // the order count is taken, the IP ban is not
+        assert!(limiter.try_acquire_at(1, 121_000).is_ok());
+        assert_eq!(limiter.try_acquire_at(1, 122_000), Err(Duration::from_millis(8_000)));
+    }
+}
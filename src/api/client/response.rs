@@ -1,9 +1,15 @@
-use std::convert::TryFrom;
-
-use super::error::HeaderParseIntError;
+use std::str::FromStr;
+use std::sync::Arc;
 
 use crate::api::client::error::BinanceError;
-use reqwest::{header::HeaderMap, StatusCode};
+#[cfg(feature = "serde_json")]
+use crate::types::time::deserialize_in;
+use crate::types::time::TimeUnit;
+use bytes::Bytes;
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    StatusCode,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -20,68 +26,250 @@ where
     #[inline(always)]
     pub fn to_result(
         self,
+        host: Arc<str>,
         status: StatusCode,
         headers: BinanceHttpHeader,
+        raw: Option<Bytes>,
+        time_unit: TimeUnit,
     ) -> Result<Response<T>, BinanceError<E>> {
         match self {
             SuccessOrError::Success(result) => Ok(Response {
+                host,
                 headers,
                 status,
                 payload: result,
+                raw,
+                time_unit,
             }),
             SuccessOrError::Error(err) => Err(BinanceError::Binance(Response {
+                host,
                 headers,
                 status,
                 payload: err,
+                raw,
+                time_unit,
             })),
         }
     }
 }
 
-#[derive(Copy, Clone, Debug, Default)]
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum IntervalUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+}
+
+impl IntervalUnit {
+    #[inline]
+    pub fn as_ms(&self) -> u64 {
+        match self {
+            IntervalUnit::Second => 1_000,
+            IntervalUnit::Minute => 60_000,
+            IntervalUnit::Hour => 3_600_000,
+            IntervalUnit::Day => 86_400_000,
+        }
+    }
+
+    #[inline]
+    fn from_letter(letter: u8) -> Option<Self> {
+        match letter.to_ascii_lowercase() {
+            b's' => Some(IntervalUnit::Second),
+            b'm' => Some(IntervalUnit::Minute),
+            b'h' => Some(IntervalUnit::Hour),
+            b'd' => Some(IntervalUnit::Day),
+            _ => None,
+        }
+    }
+}
+
+/// Interval of a rate limit header, e.g. `1m` in `x-mbx-used-weight-1m`.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HeaderInterval {
+    pub num: u32,
+    pub unit: IntervalUnit,
+}
+
+impl HeaderInterval {
+    pub const fn new(num: u32, unit: IntervalUnit) -> Self {
+        HeaderInterval { num, unit }
+    }
+
+    #[inline]
+    pub fn as_ms(&self) -> u64 {
+        self.num as u64 * self.unit.as_ms()
+    }
+
+    /// Parses the `<num><unit>` suffix of a header name.
+    fn parse(suffix: &str) -> Option<Self> {
+        let (&letter, num) = suffix.as_bytes().split_last()?;
+        let num = std::str::from_utf8(num).ok()?.parse().ok()?;
+        Some(HeaderInterval {
+            num,
+            unit: IntervalUnit::from_letter(letter)?,
+        })
+    }
+}
+
+impl std::fmt::Display for HeaderInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let unit = match self.unit {
+            IntervalUnit::Second => 's',
+            IntervalUnit::Minute => 'm',
+            IntervalUnit::Hour => 'h',
+            IntervalUnit::Day => 'd',
+        };
+        write!(f, "{}{}", self.num, unit)
+    }
+}
+
+/// Counters reported per interval, sorted by interval.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IntervalCounts(Vec<(HeaderInterval, u32)>);
+
+impl IntervalCounts {
+    #[inline]
+    pub fn get(&self, interval: HeaderInterval) -> Option<u32> {
+        self.0.iter().find(|(i, _)| *i == interval).map(|(_, count)| *count)
+    }
+
+    /// Count of the interval with the given length, e.g. `60_000` matches both `1m` and `60s`.
+    #[inline]
+    pub fn get_ms(&self, interval_ms: u64) -> Option<u32> {
+        self.0.iter().find(|(i, _)| i.as_ms() == interval_ms).map(|(_, count)| *count)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (HeaderInterval, u32)> + '_ {
+        self.0.iter().copied()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn insert(&mut self, interval: HeaderInterval, count: u32) {
+        match self.0.binary_search_by(|(i, _)| i.cmp(&interval)) {
+            Ok(pos) => self.0[pos].1 = count,
+            Err(pos) => self.0.insert(pos, (interval, count)),
+        }
+    }
+}
+
+impl FromIterator<(HeaderInterval, u32)> for IntervalCounts {
+    fn from_iter<I: IntoIterator<Item = (HeaderInterval, u32)>>(iter: I) -> Self {
+        let mut counts = IntervalCounts::default();
+        for (interval, count) in iter {
+            counts.insert(interval, count);
+        }
+        counts
+    }
+}
+
+/// Rate limit headers of a response.
+///
+/// Values that are missing, not UTF-8 or not a number are left out.
+#[derive(Clone, Debug, Default)]
 pub struct BinanceHttpHeader {
     pub x_mbx_used_weight: Option<u16>,
     pub x_mbx_used_weight_1m: Option<u16>,
+    /// Seconds to wait after a 429/418 response.
     pub retry_after: Option<u16>,
+    /// `x-mbx-used-weight-<interval>` headers.
+    pub used_weight: IntervalCounts,
+    /// `x-mbx-order-count-<interval>` headers.
+    pub order_count: IntervalCounts,
 }
 
 #[derive(Debug)]
 pub struct Response<T> {
+    /// Host that served the request, differs from the configured one after a failover.
+    pub host: Arc<str>,
     pub headers: BinanceHttpHeader,
     pub status: StatusCode,
     pub payload: T,
+    /// Body the payload was decoded from, kept if `BinanceBuilder::keep_raw_body` is set.
+    pub raw: Option<Bytes>,
+    /// Resolution of the timestamps in the body, see `BinanceBuilder::time_unit`.
+    pub time_unit: TimeUnit,
 }
 
-impl TryFrom<&HeaderMap> for BinanceHttpHeader {
-    type Error = HeaderParseIntError;
+/// Only with the `serde_json` feature: borrowing from the body needs a decoder that leaves it
+/// untouched, so `parse` uses serde_json whatever the `JsonBackend` of the client.
+#[cfg(feature = "serde_json")]
+impl Response<Bytes> {
+    /// Decodes the body of a `*_raw` call into a payload borrowing from it, e.g. `OrderBookRef`.
+    ///
+    /// Timestamps are read in the [`time_unit`](Self::time_unit) of the response.
+    pub fn parse<'a, T: Deserialize<'a>>(&'a self) -> Result<T, serde_json::Error> {
+        let mut deserializer = serde_json::Deserializer::from_slice(&self.payload);
+        let payload = deserialize_in(&mut deserializer, self.time_unit)?;
+        deserializer.end()?;
+        Ok(payload)
+    }
+}
 
-    fn try_from(headers: &HeaderMap) -> Result<Self, Self::Error> {
-        let x_mbx_used_weight = match headers.get("x-mbx-used-weight") {
-            Some(val) => Some(val.to_str().unwrap().parse()?),
-            None => None,
-        };
+const USED_WEIGHT_PREFIX: &str = "x-mbx-used-weight-";
+const ORDER_COUNT_PREFIX: &str = "x-mbx-order-count-";
 
-        let x_mbx_used_weight_1m = match headers.get("x-mbx-used-weight-1m") {
-            Some(val) => Some(val.to_str().unwrap().parse()?),
-            None => None,
-        };
+#[inline]
+fn parse_value<T: FromStr>(value: &HeaderValue) -> Option<T> {
+    std::str::from_utf8(value.as_bytes()).ok()?.trim().parse().ok()
+}
+
+#[inline]
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<std::time::Duration> {
+    headers
+        .get("retry-after")
+        .and_then(parse_value)
+        .map(std::time::Duration::from_secs)
+}
 
-        let retry_after = match headers.get("retry-after") {
-            Some(val) => Some(val.to_str().unwrap().parse()?),
-            None => None,
+impl From<&HeaderMap> for BinanceHttpHeader {
+    fn from(headers: &HeaderMap) -> Self {
+        let mut header = BinanceHttpHeader {
+            x_mbx_used_weight: headers.get("x-mbx-used-weight").and_then(parse_value),
+            x_mbx_used_weight_1m: headers.get("x-mbx-used-weight-1m").and_then(parse_value),
+            retry_after: headers.get("retry-after").and_then(parse_value),
+            ..Default::default()
         };
 
-        Ok(BinanceHttpHeader {
-            x_mbx_used_weight,
-            x_mbx_used_weight_1m,
-            retry_after,
-        })
+        // header names are always lowercase
+        for (name, value) in headers.iter() {
+            let name = name.as_str();
+            let (counts, suffix) = if let Some(suffix) = name.strip_prefix(USED_WEIGHT_PREFIX) {
+                (&mut header.used_weight, suffix)
+            } else if let Some(suffix) = name.strip_prefix(ORDER_COUNT_PREFIX) {
+                (&mut header.order_count, suffix)
+            } else {
+                continue;
+            };
+            if let (Some(interval), Some(count)) = (HeaderInterval::parse(suffix), parse_value(value)) {
+                counts.insert(interval, count);
+            }
+        }
+
+        header
     }
 }
 
+/// Reads `code` from a Binance error body without deserializing it.
+pub(crate) fn peek_error_code(body: &[u8]) -> Option<i64> {
+    let body = std::str::from_utf8(body).ok()?;
+    let start = body.find("\"code\"")? + "\"code\"".len();
+    let value = body[start..].trim_start().strip_prefix(':')?.trim_start();
+    let end = value
+        .char_indices()
+        .find(|&(i, c)| !(c.is_ascii_digit() || (i == 0 && c == '-')))
+        .map_or(value.len(), |(i, _)| i);
+    value[..end].parse().ok()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::SuccessOrError;
+    use super::*;
 
     #[test]
     fn test_parse_binance_response_error_or_empty_json() {
@@ -101,4 +289,78 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_parse_raw_body_in_time_unit() {
+        use crate::schemes::spot::market::TradeRef;
+
+        let body = r#"[{"id":28457,"price":"4.00000100","qty":"12.00000000","quoteQty":"48.000012","time":1499865549590123,"isBuyerMaker":true,"isBestMatch":true}]"#;
+        let response = Response {
+            host: Arc::from("api.binance.com"),
+            headers: BinanceHttpHeader::default(),
+            status: StatusCode::OK,
+            payload: Bytes::from_static(body.as_bytes()),
+            raw: None,
+            time_unit: TimeUnit::Microsecond,
+        };
+
+        let trades: Vec<TradeRef> = response.parse().unwrap();
+        assert_eq!(trades[0].price, "4.00000100");
+        assert_eq!(trades[0].time.unit(), TimeUnit::Microsecond);
+        assert_eq!(trades[0].time.as_millis(), 1499865549590);
+    }
+
+    #[test]
+    fn test_peek_error_code() {
+        assert_eq!(
+            peek_error_code(br#"{"code": -1121, "msg": "Invalid symbol."}"#),
+            Some(-1121),
+        );
+        assert_eq!(peek_error_code(br#"{"msg":"x","code":-2010}"#), Some(-2010));
+        assert_eq!(peek_error_code(b"{}"), None);
+        assert_eq!(peek_error_code(b"<html>"), None);
+    }
+
+    #[test]
+    fn test_parse_rate_limit_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-used-weight", HeaderValue::from_static("31"));
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("31"));
+        headers.insert("x-mbx-used-weight-1s", HeaderValue::from_static("4"));
+        headers.insert("x-mbx-order-count-10s", HeaderValue::from_static("2"));
+        headers.insert("x-mbx-order-count-1d", HeaderValue::from_static("150000"));
+        headers.insert("retry-after", HeaderValue::from_static("12"));
+
+        let header = BinanceHttpHeader::from(&headers);
+        assert_eq!(header.x_mbx_used_weight, Some(31));
+        assert_eq!(header.x_mbx_used_weight_1m, Some(31));
+        assert_eq!(header.retry_after, Some(12));
+
+        let second = HeaderInterval::new(1, IntervalUnit::Second);
+        let minute = HeaderInterval::new(1, IntervalUnit::Minute);
+        assert_eq!(header.used_weight.iter().collect::<Vec<_>>(), vec![(second, 4), (minute, 31)]);
+        assert_eq!(header.used_weight.get_ms(60_000), Some(31));
+        assert_eq!(header.order_count.get(HeaderInterval::new(10, IntervalUnit::Second)), Some(2));
+        assert_eq!(header.order_count.get(HeaderInterval::new(1, IntervalUnit::Day)), Some(150_000));
+        assert_eq!(header.order_count.get_ms(60_000), None);
+    }
+
+    #[test]
+    fn test_parse_malformed_rate_limit_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-used-weight", HeaderValue::from_bytes(b"\xff\xfe").unwrap());
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("-1"));
+        headers.insert("x-mbx-used-weight-1w", HeaderValue::from_static("5"));
+        headers.insert("x-mbx-used-weight-m", HeaderValue::from_static("5"));
+        headers.insert("x-mbx-order-count-10s", HeaderValue::from_static("many"));
+        headers.insert("retry-after", HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"));
+
+        let header = BinanceHttpHeader::from(&headers);
+        assert_eq!(header.x_mbx_used_weight, None);
+        assert_eq!(header.x_mbx_used_weight_1m, None);
+        assert_eq!(header.retry_after, None);
+        assert!(header.used_weight.is_empty());
+        assert!(header.order_count.is_empty());
+    }
 }
@@ -0,0 +1,259 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use http::Method;
+use reqwest::StatusCode;
+
+pub(crate) static RETRY_BASE_DELAY_MS_DEFAULT: u64 = 100;
+pub(crate) static RETRY_MAX_DELAY_MS_DEFAULT: u64 = 5000;
+
+#[inline]
+pub(crate) fn is_idempotent(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+/// Why a failed attempt is going to be retried.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RetryReason {
+    /// The connection could not be established, the request never reached the server.
+    Connect,
+    /// The request timed out or the connection broke after it was sent.
+    Transport,
+    /// The server answered with a retryable status: 5xx, or 429 with a `Retry-After`.
+    Status(StatusCode),
+}
+
+/// Passed to the `on_retry` callback for every attempt that is retried.
+#[derive(Clone, Debug)]
+pub struct RetryEvent<'a> {
+    pub method: &'a Method,
+    pub path: &'a str,
+    /// Number of the failed attempt, starting from 1.
+    pub attempt: u32,
+    pub reason: RetryReason,
+    /// Time to wait before the next attempt.
+    pub delay: Duration,
+}
+
+pub type OnRetry = Arc<dyn Fn(&RetryEvent) + Send + Sync>;
+
+/// Retry policy for failed requests.
+///
+/// `GET` requests are retried on transport failures, 5xx responses and 429 responses
+/// with a `Retry-After`. A 429 without it is left to the rate limiter, and a 418 (IP ban)
+/// is never retried. Non-idempotent `POST`/`PUT`/`DELETE` requests are retried only
+/// when the connection could not be established, so an order is never sent twice.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    on_retry: Option<OnRetry>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(RETRY_BASE_DELAY_MS_DEFAULT),
+            max_delay: Duration::from_millis(RETRY_MAX_DELAY_MS_DEFAULT),
+            jitter: true,
+            on_retry: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .field("on_retry", &self.on_retry.is_some())
+            .finish()
+    }
+}
+
+impl RetryPolicy {
+    /// Policy with `max_attempts` attempts in total, including the first one.
+    pub fn new(max_attempts: u32) -> Self {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            ..Default::default()
+        }
+    }
+
+    /// Never retry.
+    pub fn none() -> Self {
+        RetryPolicy::new(1)
+    }
+
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Upper bound of the backoff. A `Retry-After` longer than this is not waited for.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    pub fn jitter(mut self, enabled: bool) -> Self {
+        self.jitter = enabled;
+        self
+    }
+
+    /// Called before sleeping for every attempt that is going to be retried.
+    pub fn on_retry<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&RetryEvent) + Send + Sync + 'static,
+    {
+        self.on_retry = Some(Arc::new(callback));
+        self
+    }
+
+    #[inline]
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    #[inline]
+    pub(crate) fn notify(&self, event: &RetryEvent) {
+        if let Some(callback) = self.on_retry.as_ref() {
+            callback(event);
+        }
+    }
+
+    /// Delay before retrying after the failed `attempt`, or `None` if the request must not be retried.
+    pub(crate) fn retry_delay(
+        &self,
+        method: &Method,
+        attempt: u32,
+        reason: RetryReason,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        let idempotent = is_idempotent(method);
+        let retryable = match reason {
+            RetryReason::Connect => true,
+            RetryReason::Transport => idempotent,
+            RetryReason::Status(status) => {
+                idempotent
+                    && (status.is_server_error()
+                        || (status == StatusCode::TOO_MANY_REQUESTS && retry_after.is_some()))
+            }
+        };
+        if !retryable {
+            return None;
+        }
+
+        if let Some(retry_after) = retry_after {
+            return if retry_after <= self.max_delay {
+                Some(retry_after)
+            } else {
+                None
+            };
+        }
+
+        Some(self.backoff(attempt))
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(1_u32.checked_shl(attempt - 1).unwrap_or(u32::MAX));
+        let delay = exp.min(self.max_delay);
+
+        if self.jitter {
+            // "equal jitter": half of the delay is fixed, the other half is random
+            let half = delay / 2;
+            half + half.mul_f64(random_unit())
+        } else {
+            delay
+        }
+    }
+}
+
+fn random_unit() -> f64 {
+    use ring::rand::SecureRandom;
+
+    let mut buf = [0_u8; 4];
+    if ring::rand::SystemRandom::new().fill(&mut buf).is_err() {
+        return 1.0;
+    }
+    u32::from_le_bytes(buf) as f64 / u32::MAX as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay_respects_idempotency() {
+        let policy = RetryPolicy::new(3)
+            .base_delay(Duration::from_millis(100))
+            .jitter(false);
+        let unavailable = RetryReason::Status(StatusCode::SERVICE_UNAVAILABLE);
+
+        assert_eq!(
+            policy.retry_delay(&Method::GET, 1, unavailable, None),
+            Some(Duration::from_millis(100)),
+        );
+        assert_eq!(
+            policy.retry_delay(&Method::GET, 2, RetryReason::Transport, None),
+            Some(Duration::from_millis(200)),
+        );
+        assert_eq!(policy.retry_delay(&Method::GET, 3, unavailable, None), None);
+
+        assert_eq!(policy.retry_delay(&Method::POST, 1, unavailable, None), None);
+        assert_eq!(policy.retry_delay(&Method::DELETE, 1, RetryReason::Transport, None), None);
+        assert_eq!(
+            policy.retry_delay(&Method::POST, 1, RetryReason::Connect, None),
+            Some(Duration::from_millis(100)),
+        );
+    }
+
+    #[test]
+    fn test_retry_delay_honors_retry_after() {
+        let policy = RetryPolicy::new(5).max_delay(Duration::from_secs(10));
+        let too_many = RetryReason::Status(StatusCode::TOO_MANY_REQUESTS);
+
+        assert_eq!(
+            policy.retry_delay(&Method::GET, 1, too_many, Some(Duration::from_secs(3))),
+            Some(Duration::from_secs(3)),
+        );
+        assert_eq!(
+            policy.retry_delay(&Method::GET, 1, too_many, Some(Duration::from_secs(60))),
+            None,
+        );
+        assert_eq!(policy.retry_delay(&Method::GET, 1, too_many, None), None);
+    }
+
+    #[test]
+    fn test_retry_delay_never_retries_ip_ban() {
+        let policy = RetryPolicy::new(5).max_delay(Duration::from_secs(10));
+        let banned = RetryReason::Status(StatusCode::IM_A_TEAPOT);
+
+        assert_eq!(policy.retry_delay(&Method::GET, 1, banned, None), None);
+        assert_eq!(policy.retry_delay(&Method::GET, 1, banned, Some(Duration::from_secs(3))), None);
+    }
+
+    #[test]
+    fn test_backoff_jitter_stays_in_range() {
+        let policy = RetryPolicy::new(10)
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(1000));
+
+        for attempt in 1..10 {
+            let delay = policy.backoff(attempt);
+            assert!(delay <= Duration::from_millis(1000));
+            assert!(delay >= Duration::from_millis(50));
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use super::rate_limit::RateLimiter;
+use super::transport::Transport;
+
+/// Weight accounting of a source address.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SourceIpStatus {
+    pub addr: IpAddr,
+    /// Free weight in the window closest to its limit.
+    pub headroom: u32,
+}
+
+pub(crate) struct SourceIp {
+    pub(crate) addr: IpAddr,
+    pub(crate) transport: Arc<dyn Transport>,
+    pub(crate) limiter: RateLimiter,
+}
+
+/// Local addresses requests are sent from, with one HTTP client and one weight budget each.
+///
+/// Binance counts request weight per source IP. Every request goes out from the
+/// address with the most headroom, which is resynced from the response headers.
+pub struct SourceIpPool {
+    sources: Vec<SourceIp>,
+    /// Whether the limiters wait or fail like the client rate limiter,
+    /// otherwise they only count weight to pick the address.
+    enforce: bool,
+}
+
+impl SourceIpPool {
+    pub(crate) fn new(sources: Vec<SourceIp>, enforce: bool) -> Self {
+        SourceIpPool { sources, enforce }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    pub fn status(&self) -> Vec<SourceIpStatus> {
+        self.sources
+            .iter()
+            .map(|source| SourceIpStatus {
+                addr: source.addr,
+                headroom: source.limiter.headroom(),
+            })
+            .collect()
+    }
+
+    /// Weight accounting of the address, if it is in the pool.
+    pub fn limiter(&self, addr: IpAddr) -> Option<&RateLimiter> {
+        self.sources
+            .iter()
+            .find(|source| source.addr == addr)
+            .map(|source| &source.limiter)
+    }
+
+    #[inline]
+    pub(crate) fn enforce(&self) -> bool {
+        self.enforce
+    }
+
+    #[inline]
+    pub(crate) fn sources(&self) -> &[SourceIp] {
+        &self.sources
+    }
+
+    /// The address with the most headroom, the first one on ties.
+    pub(crate) fn select(&self) -> &SourceIp {
+        self.sources
+            .iter()
+            .enumerate()
+            .max_by_key(|(idx, source)| (source.limiter.headroom(), std::cmp::Reverse(*idx)))
+            .map(|(_, source)| source)
+            .expect("source IP pool is never empty")
+    }
+}
+
+impl std::fmt::Debug for SourceIpPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("SourceIpPool")
+            .field("addrs", &self.sources.iter().map(|source| source.addr).collect::<Vec<_>>())
+            .field("enforce", &self.enforce)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::client::rate_limit::{RateLimitMode, WeightLimit};
+    use crate::api::client::transport::ReqwestTransport;
+
+    #[test]
+    fn test_select_source_with_most_headroom() {
+        let transport: Arc<dyn Transport> = Arc::new(ReqwestTransport::new(reqwest::Client::new()));
+        let addrs: [IpAddr; 3] = ["10.0.0.1".parse().unwrap(), "10.0.0.2".parse().unwrap(), "10.0.0.3".parse().unwrap()];
+        let pool = SourceIpPool::new(
+            addrs
+                .iter()
+                .map(|addr| SourceIp {
+                    addr: *addr,
+                    transport: transport.clone(),
+                    limiter: RateLimiter::new(RateLimitMode::Wait, &[WeightLimit::per_minute(100)]),
+                })
+                .collect(),
+            false,
+        );
+
+        assert_eq!(pool.select().addr, addrs[0]);
+        pool.select().limiter.reserve(10);
+        assert_eq!(pool.select().addr, addrs[1]);
+        pool.select().limiter.reserve(20);
+        assert_eq!(pool.select().addr, addrs[2]);
+        pool.select().limiter.reserve(5);
+        assert_eq!(pool.select().addr, addrs[2]);
+
+        assert_eq!(
+            pool.status().iter().map(|status| status.headroom).collect::<Vec<_>>(),
+            vec![90, 80, 95],
+        );
+        assert!(pool.limiter(addrs[1]).is_some());
+    }
+}
@@ -0,0 +1,25 @@
+use tokio::task::JoinHandle;
+
+/// Background tasks spawned for a client, aborted when the last clone of the client is dropped.
+#[derive(Debug, Default)]
+pub struct BackgroundTasks {
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl BackgroundTasks {
+    pub fn push(&mut self, handle: JoinHandle<()>) {
+        self.handles.push(handle);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+}
+
+impl Drop for BackgroundTasks {
+    fn drop(&mut self) {
+        for handle in self.handles.iter() {
+            handle.abort();
+        }
+    }
+}
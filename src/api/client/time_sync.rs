@@ -0,0 +1,75 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+use super::BinanceClient;
+
+/// Estimated difference between the Binance server clock and the local clock.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct TimeOffset {
+    /// `server_time - local_time` in milliseconds.
+    pub offset_ms: i64,
+    /// Round-trip time of the request used for the estimate, in milliseconds.
+    pub rtt_ms: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct ServerTimeSync {
+    offset_ms: AtomicI64,
+    rtt_ms: AtomicI64,
+}
+
+impl ServerTimeSync {
+    #[inline]
+    pub fn offset(&self) -> TimeOffset {
+        TimeOffset {
+            offset_ms: self.offset_ms.load(Ordering::Relaxed),
+            rtt_ms: self.rtt_ms.load(Ordering::Relaxed),
+        }
+    }
+
+    #[inline]
+    pub fn offset_ms(&self) -> i64 {
+        self.offset_ms.load(Ordering::Relaxed)
+    }
+
+    /// Updates the estimate from one `/time` round trip.
+    ///
+    /// The server is assumed to have read its clock halfway through the round trip.
+    pub fn update(&self, sent_ms: i64, server_time_ms: i64, received_ms: i64) -> TimeOffset {
+        let rtt_ms = (received_ms - sent_ms).max(0);
+        let offset_ms = server_time_ms - (sent_ms + rtt_ms / 2);
+
+        self.offset_ms.store(offset_ms, Ordering::Relaxed);
+        self.rtt_ms.store(rtt_ms, Ordering::Relaxed);
+
+        TimeOffset { offset_ms, rtt_ms }
+    }
+}
+
+pub(crate) async fn run_server_time_sync(client: BinanceClient, path: &'static str, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        // A failed sync keeps the previous offset, the next tick tries again.
+        let _ = client.sync_server_time::<()>(path).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_offset_from_round_trip() {
+        let sync = ServerTimeSync::default();
+
+        let offset = sync.update(1_000, 1_550, 1_100);
+        assert_eq!(offset, TimeOffset { offset_ms: 500, rtt_ms: 100 });
+        assert_eq!(sync.offset(), offset);
+
+        let offset = sync.update(2_000, 1_990, 2_020);
+        assert_eq!(offset, TimeOffset { offset_ms: -20, rtt_ms: 20 });
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt;
+use std::future::Future;
+use std::time::Instant;
+
+use http::Method;
+use tracing::field::Empty;
+
+const REDACTED: &str = "<redacted>";
+
+/// Span wrapping one endpoint call, including all of its retries.
+///
+/// Never records the API key: it only travels in the `x-mbx-apikey` header, which is not traced.
+#[inline]
+pub(crate) fn request_span(method: &Method, path: &str) -> tracing::Span {
+    tracing::info_span!(
+        "binance_request",
+        method = %method,
+        path,
+        host = Empty,
+        attempts = Empty,
+        status = Empty,
+        used_weight = Empty,
+        error_code = Empty,
+        latency_ms = Empty,
+    )
+}
+
+/// Runs `fut` in `span` and records its latency.
+pub(crate) async fn instrument<F: Future>(fut: F, span: tracing::Span) -> F::Output {
+    let started = Instant::now();
+    let output = tracing::Instrument::instrument(fut, span.clone()).await;
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+    output
+}
+
+/// Displays a URL with the value of the `signature` parameter replaced.
+pub(crate) struct RedactedUrl<'a>(pub &'a str);
+
+impl fmt::Display for RedactedUrl<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let url = self.0;
+        let (base, query) = match url.find('?') {
+            Some(pos) => url.split_at(pos + 1),
+            None => return f.write_str(url),
+        };
+        f.write_str(base)?;
+
+        for (i, param) in query.split('&').enumerate() {
+            if i > 0 {
+                f.write_str("&")?;
+            }
+            match param.split_once('=') {
+                Some(("signature", _)) => write!(f, "signature={}", REDACTED)?,
+                _ => f.write_str(param)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacted_url_hides_signature() {
+        let url = "https://api.binance.com/api/v3/order?symbol=BTCUSDT&timestamp=1499827319559\
+                   &signature=c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71";
+        assert_eq!(
+            RedactedUrl(url).to_string(),
+            "https://api.binance.com/api/v3/order?symbol=BTCUSDT&timestamp=1499827319559\
+             &signature=<redacted>",
+        );
+
+        let url = "https://api.binance.com/api/v3/ping";
+        assert_eq!(RedactedUrl(url).to_string(), url);
+    }
+}
@@ -0,0 +1,165 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use bytes::Bytes;
+use http::Method;
+use reqwest::{header::HeaderMap, Client, StatusCode};
+
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<TransportResponse, TransportError>> + Send + 'a>>;
+
+/// A request ready to be put on the wire: the query is already signed.
+#[derive(Debug)]
+pub struct TransportRequest<'a> {
+    pub method: &'a Method,
+    pub url: &'a str,
+    pub headers: &'a HeaderMap,
+    pub body: Option<&'a [u8]>,
+    /// Overrides the timeout of the transport for this request.
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug)]
+pub struct TransportResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TransportErrorKind {
+    /// The connection could not be established, the request never reached the server.
+    Connect,
+    Timeout,
+    Other,
+}
+
+#[derive(Debug)]
+pub struct TransportError {
+    kind: TransportErrorKind,
+    source: BoxError,
+}
+
+impl TransportError {
+    pub fn new<E: Into<BoxError>>(kind: TransportErrorKind, source: E) -> Self {
+        TransportError {
+            kind,
+            source: source.into(),
+        }
+    }
+
+    pub fn connect<E: Into<BoxError>>(source: E) -> Self {
+        TransportError::new(TransportErrorKind::Connect, source)
+    }
+
+    pub fn timeout<E: Into<BoxError>>(source: E) -> Self {
+        TransportError::new(TransportErrorKind::Timeout, source)
+    }
+
+    pub fn other<E: Into<BoxError>>(source: E) -> Self {
+        TransportError::new(TransportErrorKind::Other, source)
+    }
+
+    #[inline]
+    pub fn kind(&self) -> TransportErrorKind {
+        self.kind
+    }
+
+    #[inline]
+    pub fn is_connect(&self) -> bool {
+        self.kind == TransportErrorKind::Connect
+    }
+
+    #[inline]
+    pub fn is_timeout(&self) -> bool {
+        self.kind == TransportErrorKind::Timeout
+    }
+
+    pub fn into_source(self) -> BoxError {
+        self.source
+    }
+}
+
+impl std::fmt::Display for TransportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.kind {
+            TransportErrorKind::Connect => write!(f, "connect error: {}", self.source),
+            TransportErrorKind::Timeout => write!(f, "timeout: {}", self.source),
+            TransportErrorKind::Other => write!(f, "transport error: {}", self.source),
+        }
+    }
+}
+
+impl std::error::Error for TransportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+impl From<reqwest::Error> for TransportError {
+    fn from(err: reqwest::Error) -> Self {
+        let kind = if err.is_connect() {
+            TransportErrorKind::Connect
+        } else if err.is_timeout() {
+            TransportErrorKind::Timeout
+        } else {
+            TransportErrorKind::Other
+        };
+        TransportError::new(kind, err)
+    }
+}
+
+/// Sends HTTP requests for the client.
+///
+/// Timeouts, connection pooling and TLS are up to the implementation.
+pub trait Transport: Send + Sync {
+    fn send<'a>(&'a self, request: TransportRequest<'a>) -> TransportFuture<'a>;
+}
+
+/// The default transport.
+#[derive(Clone, Debug)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> Self {
+        ReqwestTransport { client }
+    }
+
+    #[inline]
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send<'a>(&'a self, request: TransportRequest<'a>) -> TransportFuture<'a> {
+        let mut builder = self
+            .client
+            .request(request.method.clone(), request.url)
+            .headers(request.headers.clone());
+        if let Some(body) = request.body {
+            builder = builder.body(body.to_vec());
+        }
+        if let Some(timeout) = request.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        Box::pin(async move {
+            let mut resp = builder.send().await?;
+            let status = resp.status();
+            let headers = std::mem::take(resp.headers_mut());
+            let body = resp.bytes().await?;
+
+            Ok(TransportResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
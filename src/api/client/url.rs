@@ -1,5 +1,5 @@
-use super::hmac::gen_signature_to_slice;
 use super::query_string::to_query_string_writer;
+use super::signer::{Signer, SignerError};
 
 use rust_decimal::Decimal;
 use serde::ser::Serialize;
@@ -14,6 +14,10 @@ fn add_param_separator(this: &mut Url) {
     this.url.push_str("&");
 }
 
+fn add_body_separator(this: &mut Url) {
+    this.add_separator_fn = add_param_separator;
+}
+
 // pub trait UrlEncodedParams {
 //     fn add_params(&mut self, params: &str);
 //     fn add_param_str(&mut self, key: &str, val: &str);
@@ -29,10 +33,28 @@ fn add_param_separator(this: &mut Url) {
 
 pub struct Url {
     url: String,
+    path_start_pos: usize,
     query_start_pos: Option<usize>,
+    body_start_pos: Option<usize>,
     add_separator_fn: fn(&mut Self),
 }
 
+impl Clone for Url {
+    fn clone(&self) -> Self {
+        // keep the capacity reserved for the signed params
+        let mut url = String::with_capacity(self.url.capacity());
+        url.push_str(&self.url);
+
+        Url {
+            url,
+            path_start_pos: self.path_start_pos,
+            query_start_pos: self.query_start_pos,
+            body_start_pos: self.body_start_pos,
+            add_separator_fn: self.add_separator_fn,
+        }
+    }
+}
+
 impl Url {
     pub fn with_capacity(host: &str, path: &str, size: usize) -> Self {
         let mut url = String::with_capacity(size);
@@ -41,11 +63,50 @@ impl Url {
 
         Url {
             url,
+            path_start_pos: host.len(),
             query_start_pos: None,
+            body_start_pos: None,
             add_separator_fn: add_query_separator,
         }
     }
 
+    /// Params added from now on, including the signed params, go to
+    /// the `application/x-www-form-urlencoded` body instead of the query.
+    ///
+    /// The body directly follows the query in the buffer, so the signature is computed
+    /// over the query concatenated with the body, as Binance expects.
+    pub fn start_body(&mut self) {
+        if self.body_start_pos.is_some() {
+            return;
+        }
+        if self.query_start_pos.is_none() {
+            add_query_separator(self);
+        }
+        self.body_start_pos = Some(self.url.len());
+        self.add_separator_fn = add_body_separator;
+    }
+
+    /// Copy of the URL sent to another host.
+    pub fn with_host(&self, host: &str) -> Self {
+        let mut url = String::with_capacity(self.url.capacity() - self.path_start_pos + host.len());
+        url.push_str(host);
+        url.push_str(&self.url[self.path_start_pos..]);
+
+        let shift = |pos: usize| pos - self.path_start_pos + host.len();
+        Url {
+            url,
+            path_start_pos: host.len(),
+            query_start_pos: self.query_start_pos.map(shift),
+            body_start_pos: self.body_start_pos.map(shift),
+            add_separator_fn: self.add_separator_fn,
+        }
+    }
+
+    #[inline]
+    pub fn host(&self) -> &str {
+        &self.url[..self.path_start_pos]
+    }
+
     #[inline(always)]
     fn add_separator(&mut self) {
         (self.add_separator_fn)(self);
@@ -107,8 +168,8 @@ impl Url {
     }
 
     #[inline(always)]
-    pub fn add_timestamp(&mut self) {
-        self.add_param_integer("timestamp", chrono::Utc::now().timestamp_millis());
+    pub fn add_timestamp(&mut self, timestamp: i64) {
+        self.add_param_integer("timestamp", timestamp);
     }
 
     #[inline]
@@ -118,26 +179,47 @@ impl Url {
     }
 
     #[inline]
-    pub fn gen_and_add_signature(&mut self, secret_key: &ring::hmac::Key) {
-        unsafe {
-            let mut buf = [0_u8; 64];
-            gen_signature_to_slice(
-                self.url[self.query_start_pos.unwrap()..].as_bytes(),
-                &mut buf,
-                secret_key,
-            ).unwrap();
-            self.url.push_str("&signature=");
-            self.url.push_str(std::str::from_utf8_unchecked(&buf));
+    pub fn gen_and_add_signature(&mut self, signer: &dyn Signer) -> Result<(), SignerError> {
+        let mut signature = String::with_capacity(signer.signature_len());
+        signer.sign(
+            &self.url.as_bytes()[self.query_start_pos.unwrap_or(self.url.len())..],
+            &mut signature,
+        )?;
+        self.add_signature(&signature);
+        Ok(())
+    }
+
+    #[inline]
+    pub fn path(&self) -> &str {
+        match self.query_start_pos {
+            Some(pos) => &self.url[self.path_start_pos..pos - 1],
+            None => &self.url[self.path_start_pos..],
         }
     }
 
     #[inline]
     pub fn get_query(&self) -> Option<&str> {
-        if let Some(pos) = self.query_start_pos {
-            return Some(&self.url[pos..]);
+        match (self.query_start_pos, self.body_start_pos) {
+            (Some(pos), Some(body_pos)) if pos == body_pos => None,
+            (Some(pos), Some(body_pos)) => Some(&self.url[pos..body_pos]),
+            (Some(pos), None) => Some(&self.url[pos..]),
+            _ => None,
+        }
+    }
+
+    /// The URL to send, without the body params.
+    #[inline]
+    pub fn request_url(&self) -> &str {
+        match (self.query_start_pos, self.body_start_pos) {
+            (Some(pos), Some(body_pos)) if pos == body_pos => &self.url[..pos - 1],
+            (_, Some(body_pos)) => &self.url[..body_pos],
+            _ => &self.url,
         }
+    }
 
-        None
+    #[inline]
+    pub fn body(&self) -> Option<&str> {
+        self.body_start_pos.map(|pos| &self.url[pos..])
     }
 
     #[inline(always)]
@@ -294,3 +376,67 @@ impl Url {
 //         "".to_owned()
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::client::signer::HmacSigner;
+
+    #[test]
+    fn test_signature_over_query_and_body() {
+        // https://binance-docs.github.io/apidocs/spot/en/#signed-trade-user_data-and-margin-endpoint-security
+        let signer = HmacSigner::new(b"NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j");
+
+        let mut url = Url::with_capacity("https://api.binance.com", "/api/v3/order", 256);
+        url.add_params("symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC");
+        url.start_body();
+        url.add_param_str("quantity", "1");
+        url.add_param_str("price", "0.1");
+        url.add_recv_window(5000);
+        url.add_timestamp(1499827319559);
+        url.gen_and_add_signature(&signer).unwrap();
+
+        assert_eq!(url.path(), "/api/v3/order");
+        assert_eq!(
+            url.request_url(),
+            "https://api.binance.com/api/v3/order?symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC",
+        );
+        assert_eq!(url.get_query(), Some("symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC"));
+        assert_eq!(
+            url.body(),
+            Some(
+                "quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559\
+                 &signature=0fd168b8ddb4876a0358a8d14d0c9f3da0e9b20c5d52b2a00fcf7d1c602f9a77"
+            ),
+        );
+    }
+
+    #[test]
+    fn test_with_host() {
+        let mut url = Url::with_capacity("https://api.binance.com", "/api/v3/order", 256);
+        url.add_param_str("symbol", "BTCUSDT");
+        url.start_body();
+        url.add_param_str("side", "BUY");
+
+        let mut url = url.with_host("https://api-gcp.binance.com");
+        url.add_timestamp(1499827319559);
+
+        assert_eq!(url.host(), "https://api-gcp.binance.com");
+        assert_eq!(url.path(), "/api/v3/order");
+        assert_eq!(url.request_url(), "https://api-gcp.binance.com/api/v3/order?symbol=BTCUSDT");
+        assert_eq!(url.body(), Some("side=BUY&timestamp=1499827319559"));
+    }
+
+    #[test]
+    fn test_body_only() {
+        let mut url = Url::with_capacity("https://api.binance.com", "/api/v3/order", 256);
+        url.start_body();
+        url.add_param_str("symbol", "BTCUSDT");
+        url.add_timestamp(1499827319559);
+
+        assert_eq!(url.path(), "/api/v3/order");
+        assert_eq!(url.request_url(), "https://api.binance.com/api/v3/order");
+        assert_eq!(url.get_query(), None);
+        assert_eq!(url.body(), Some("symbol=BTCUSDT&timestamp=1499827319559"));
+    }
+}
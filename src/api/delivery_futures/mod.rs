@@ -1,8 +1,15 @@
 pub mod market;
 
+use crate::api::client::credentials::SecretString;
 use crate::api::client::error::BinanceError;
+use crate::api::client::hosts::HostPool;
+use crate::api::client::proxy::ProxyConfig;
+use crate::api::client::rate_limit::{RateLimiter, WeightLimit};
+use crate::api::client::source_ip::SourceIpPool;
+use crate::api::client::time_sync::TimeOffset;
 use crate::api::client::BinanceClient;
 use crate::api::{BinanceBuilder, FromBinanceClient};
+use crate::schemes::error::FuturesError;
 
 pub const DELIVERY_FUTURES_API: &'static str = "https://dapi.binance.com";
 
@@ -24,8 +31,8 @@ impl BinanceDeliveryFutures {
 
     pub fn signed<K1, K2>(api_key: K1, secret_key: K2) -> Result<Self, BinanceError<()>>
     where
-        K1: Into<String>,
-        K2: Into<String>,
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
     {
         Ok(Self {
             client: BinanceClient::signed(DELIVERY_FUTURES_API.to_owned(), api_key.into(), secret_key.into())?,
@@ -45,19 +52,84 @@ impl BinanceDeliveryFutures {
     ) -> Result<Self, BinanceError<()>>
     where
         S: Into<String>,
-        K1: Into<String>,
-        K2: Into<String>,
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
     {
         Ok(Self {
             client: BinanceClient::signed(host.into(), api_key.into(), secret_key.into())?,
         })
     }
+
+    /// Measures the offset between the local and the server clock.
+    /// The offset is applied to the timestamp of every following signed request.
+    pub async fn sync_server_time(&self) -> Result<TimeOffset, BinanceError<FuturesError>> {
+        self.client
+            .sync_server_time(market::endpoints::DAPI_V1_TIME)
+            .await
+    }
+
+    /// Last measured server clock offset and round-trip time.
+    pub fn time_offset(&self) -> TimeOffset {
+        self.client.time_offset()
+    }
+
+    /// Loads the `REQUEST_WEIGHT` and `ORDERS` limits of the client side rate limiters from `exchangeInfo`.
+    pub async fn sync_rate_limits(&self) -> Result<(), BinanceError<FuturesError>> {
+        let resp = self.get_exchange_info().await?;
+        self.client.set_exchange_limits(&resp.payload.rate_limits);
+        Ok(())
+    }
+
+    /// Client side weight accounting, if enabled with `BinanceBuilder::rate_limiter`.
+    /// With `BinanceBuilder::bind_local_addresses` the weight is kept per address instead.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.client.rate_limiter()
+    }
+
+    /// Order count of the account, if enabled with `BinanceBuilder::order_rate_limiter`.
+    pub fn order_rate_limiter(&self) -> Option<&RateLimiter> {
+        self.client.order_rate_limiter()
+    }
+
+    /// Local addresses set with `BinanceBuilder::bind_local_addresses` and their weight accounting.
+    pub fn source_ip_pool(&self) -> Option<&SourceIpPool> {
+        self.client.source_ip_pool()
+    }
+
+    /// Proxy set with `BinanceBuilder::proxy`.
+    pub fn proxy(&self) -> Option<&ProxyConfig> {
+        self.client.proxy()
+    }
+
+    /// Pings every host set with `BinanceBuilder::hosts` and updates its health and latency.
+    pub async fn check_hosts(&self) {
+        self.client.check_hosts(market::endpoints::DAPI_V1_PING).await
+    }
+
+    /// Opens up to `connections` pooled connections to the current host with concurrent pings,
+    /// see `BinanceBuilder::warm_up_connections`. Returns how many pings succeeded.
+    pub async fn warm_up(&self, connections: usize) -> usize {
+        self.client.warm_up(market::endpoints::DAPI_V1_PING, connections).await
+    }
+
+    /// Hosts of the client, if several are set with `BinanceBuilder::hosts`.
+    pub fn host_pool(&self) -> Option<&HostPool> {
+        self.client.host_pool()
+    }
 }
 
 impl FromBinanceClient for BinanceDeliveryFutures {
     const HOST: &'static str = DELIVERY_FUTURES_API;
+    const SERVER_TIME_PATH: &'static str = market::endpoints::DAPI_V1_TIME;
+    const PING_PATH: &'static str = market::endpoints::DAPI_V1_PING;
+    const REQUEST_WEIGHT_LIMIT_1M: u32 = 2400;
+    const ORDER_LIMITS: &'static [WeightLimit] = &[WeightLimit { interval_ms: 60_000, limit: 1200 }];
 
     fn from_client(client: BinanceClient) -> Self {
         Self { client }
     }
+
+    fn client(&self) -> &BinanceClient {
+        &self.client
+    }
 }
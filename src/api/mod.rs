@@ -1,3 +1,6 @@
+mod accounts;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 mod client;
 pub mod delivery_futures;
 pub mod perpetual_futures;
@@ -5,29 +8,95 @@ pub mod spot;
 
 use std::marker::PhantomData;
 use std::net::IpAddr;
+use std::sync::Arc;
 use std::time::Duration;
 
 use client::{BinanceClientBuilder, BinanceClient};
+use crate::types::time::TimeUnit;
 
+pub use accounts::{AccountError, AccountProfile, AccountRegistry, AccountRegistryBuilder};
+pub use client::clock::{Clock, FixedClock, ManualClock, SystemClock};
+pub use client::credentials::{ApiKey, CredentialsError, SecretBytes, SecretKey, SecretString};
 pub use client::error::BinanceError;
+pub use client::hosts::{HostPool, HostSelection, HostStatus};
+pub use client::interceptor::{Interceptor, RequestParts, ResponseMeta};
+pub use client::json::{DynJsonDecoder, JsonBackend, JsonDecoder, JsonError};
+#[cfg(feature = "serde_json")]
+pub use client::json::SerdeJsonDecoder;
+#[cfg(feature = "simd_json")]
+pub use client::json::SimdJsonDecoder;
+#[cfg(feature = "sonic_rs")]
+pub use client::json::SonicRsDecoder;
+pub use client::metrics::{LatencyHistogram, MetricsRegistry, MetricsSink, RequestMetrics};
+pub use client::options::RequestOptions;
+pub use client::proxy::ProxyConfig;
+pub use client::rate_limit::{LimitKind, RateLimitMode, RateLimiter, WeightLimit};
+pub use client::response::{BinanceHttpHeader, HeaderInterval, IntervalCounts, IntervalUnit, Response};
+pub use client::retry::{RetryEvent, RetryPolicy, RetryReason};
+pub use client::source_ip::{SourceIpPool, SourceIpStatus};
+pub use client::signer::{Ed25519Signer, HmacSigner, RsaSigner, Signer, SignerError, SigningKey};
+pub use client::time_sync::TimeOffset;
+pub use client::transport::{
+    ReqwestTransport, Transport, TransportError, TransportErrorKind, TransportFuture, TransportRequest,
+    TransportResponse,
+};
 pub use delivery_futures::BinanceDeliveryFutures;
 pub use perpetual_futures::BinancePerpFutures;
 pub use spot::BinanceSpot;
 
 pub trait FromBinanceClient {
     const HOST: &'static str;
+    const SERVER_TIME_PATH: &'static str;
+    const PING_PATH: &'static str;
+    /// Default `REQUEST_WEIGHT` limit per minute, until it is synced from `exchangeInfo`.
+    const REQUEST_WEIGHT_LIMIT_1M: u32;
+    /// Default `ORDERS` limits of an account, until they are synced from `exchangeInfo`.
+    const ORDER_LIMITS: &'static [WeightLimit];
 
     fn from_client(client: BinanceClient) -> Self;
+
+    fn client(&self) -> &BinanceClient;
+
+    /// Builds the client, its background tasks are spawned on the current tokio runtime.
+    fn build(builder: BinanceClientBuilder) -> Result<Self, BinanceError<()>>
+    where
+        Self: Sized,
+    {
+        Ok(Self::from_client(builder.build()?))
+    }
 }
 
 pub struct BinanceBuilder<C: FromBinanceClient> {
-    api_key: Option<String>,
-    secret_key: Option<String>,
+    host: Option<String>,
+    fallback_hosts: Vec<String>,
+    host_selection: HostSelection,
+    host_health_check_interval: Option<Duration>,
+    api_key: Option<SecretString>,
+    signing_key: Option<SigningKey>,
     recv_window: Option<u16>,
+    time_unit: TimeUnit,
     http_connect_timeout_ms: Option<Duration>,
     http_request_timeout_ms: Option<Duration>,
     tcp_nodelay: Option<bool>,
+    http2_prior_knowledge: bool,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    warm_up_connections: Option<usize>,
+    keep_alive_interval: Option<Duration>,
     local_addr: Option<IpAddr>,
+    local_addrs: Vec<IpAddr>,
+    proxy: Option<ProxyConfig>,
+    server_time_sync_interval: Option<Duration>,
+    clock: Option<Arc<dyn Clock>>,
+    rate_limit_mode: Option<RateLimitMode>,
+    order_rate_limit_mode: Option<RateLimitMode>,
+    retry_policy: Option<RetryPolicy>,
+    transport: Option<Arc<dyn Transport>>,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    metrics: Option<Arc<dyn MetricsSink>>,
+    keep_raw_body: bool,
+    json_backend: JsonBackend,
+    json_decoder: Option<Arc<dyn DynJsonDecoder>>,
     phantom: PhantomData<C>,
 }
 
@@ -37,13 +106,36 @@ where
 {
     fn default() -> Self {
         BinanceBuilder {
+            host: None,
+            fallback_hosts: Vec::new(),
+            host_selection: HostSelection::default(),
+            host_health_check_interval: None,
             api_key: None,
-            secret_key: None,
+            signing_key: None,
             recv_window: None,
+            time_unit: TimeUnit::default(),
             http_connect_timeout_ms: None,
             http_request_timeout_ms: None,
             tcp_nodelay: None,
+            http2_prior_knowledge: false,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            warm_up_connections: None,
+            keep_alive_interval: None,
             local_addr: None,
+            local_addrs: Vec::new(),
+            proxy: None,
+            server_time_sync_interval: None,
+            clock: None,
+            rate_limit_mode: None,
+            order_rate_limit_mode: None,
+            retry_policy: None,
+            transport: None,
+            interceptors: Vec::new(),
+            metrics: None,
+            keep_raw_body: false,
+            json_backend: JsonBackend::default(),
+            json_decoder: None,
             phantom: PhantomData,
         }
     }
@@ -57,13 +149,75 @@ where
         BinanceBuilder::default()
     }
 
+    pub fn host<S: Into<String>>(mut self, host: S) -> Self {
+        self.host = Some(host.into());
+        self
+    }
+
+    /// Ordered list of hosts, e.g. `SPOT_API_CLUSTERS`. Requests go to the first one
+    /// and fail over to the next ones on connect errors and 5xx responses.
+    pub fn hosts<I, S>(mut self, hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut hosts = hosts.into_iter().map(Into::into);
+        self.host = hosts.next();
+        self.fallback_hosts = hosts.collect();
+        self
+    }
+
+    /// How the host of each request is picked when several `hosts` are set.
+    pub fn host_selection(mut self, selection: HostSelection) -> Self {
+        self.host_selection = selection;
+        self
+    }
+
+    /// Periodically pings every host to update its health and latency.
+    /// The first check is made right after `build`, which must be called within a Tokio runtime.
+    pub fn host_health_check_interval(mut self, interval: Duration) -> Self {
+        self.host_health_check_interval = Some(interval);
+        self
+    }
+
     pub fn signed<K1, K2>(mut self, api_key: K1, secret_key: K2) -> Self
     where
-        K1: Into<String>,
-        K2: Into<String>,
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
     {
         self.api_key = Some(api_key.into());
-        self.secret_key = Some(secret_key.into());
+        self.signing_key = Some(SigningKey::Hmac(secret_key.into()));
+        self
+    }
+
+    /// Signs requests with an RSA key. `private_key_pem` is a PKCS#8 `PRIVATE KEY` PEM,
+    /// it is parsed by `build`.
+    pub fn signed_rsa<K1, K2>(mut self, api_key: K1, private_key_pem: K2) -> Self
+    where
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
+    {
+        self.api_key = Some(api_key.into());
+        self.signing_key = Some(SigningKey::RsaPem(private_key_pem.into()));
+        self
+    }
+
+    /// Signs requests with an Ed25519 key. `private_key_pem` is a PKCS#8 `PRIVATE KEY` PEM,
+    /// it is parsed by `build`.
+    pub fn signed_ed25519<K1, K2>(mut self, api_key: K1, private_key_pem: K2) -> Self
+    where
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
+    {
+        self.api_key = Some(api_key.into());
+        self.signing_key = Some(SigningKey::Ed25519Pem(private_key_pem.into()));
+        self
+    }
+
+    /// Signs requests with a custom signer.
+    pub fn signer<K: Into<SecretString>>(mut self, api_key: K, signer: Arc<dyn Signer>) -> Self {
+        self.api_key = Some(api_key.into());
+        self.signing_key = Some(SigningKey::Custom(signer));
         self
     }
 
@@ -87,26 +241,184 @@ where
         self
     }
 
+    /// Speaks HTTP/2 from the start instead of HTTP/1.1, requests share one connection per host.
+    /// Only for hosts known to speak h2: requests to an HTTP/1.1 only host, e.g. behind
+    /// some proxies, fail. Without it HTTP/2 is still negotiated over ALPN when the host
+    /// supports it, with `native-tls` as well as with `rustls-tls`.
+    pub fn http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.http2_prior_knowledge = enabled;
+        self
+    }
+
+    /// How long an unused connection stays in the pool, 90 seconds by default.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Maximum number of unused connections kept in the pool per host.
+    pub fn pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max_idle);
+        self
+    }
+
+    /// Opens `connections` pooled connections with concurrent `ping` calls,
+    /// so that the first requests do not pay for the TCP and TLS handshakes.
+    ///
+    /// Requires a tokio runtime: the pings are spawned by `build`, which returns before they
+    /// complete. To wait for the connections, call `warm_up(connections).await` on the built
+    /// client instead, it returns how many were opened.
+    pub fn warm_up_connections(mut self, connections: usize) -> Self {
+        self.warm_up_connections = Some(connections);
+        self
+    }
+
+    /// Pings the warm connections every `interval`, one if `warm_up_connections` is not set.
+    /// Keep it below the pool idle timeout.
+    pub fn keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// Sends requests from `addr`. With `bind_local_addresses` it is one more address of the pool.
     pub fn bind_local_address(mut self, addr: IpAddr) -> Self {
         self.local_addr = Some(addr);
         self
     }
 
+    /// Sends requests from several local addresses, each with its own HTTP client and weight budget.
+    /// Every request goes out from the address with the most headroom.
+    pub fn bind_local_addresses<I: IntoIterator<Item = IpAddr>>(mut self, addrs: I) -> Self {
+        self.local_addrs = addrs.into_iter().collect();
+        self
+    }
+
+    /// Connects through an HTTP, HTTPS or SOCKS5 proxy, e.g. a corporate egress proxy.
+    /// Not used with a custom transport.
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Periodically measures the server clock offset and applies it to signed requests.
+    /// The first measurement is made right after `build`, which must be called within a Tokio runtime.
+    pub fn server_time_sync_interval(mut self, interval: Duration) -> Self {
+        self.server_time_sync_interval = Some(interval);
+        self
+    }
+
+    /// Reads the local time of signed timestamps and of the server time sync from `clock`,
+    /// e.g. a `FixedClock` to check signed requests in tests.
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Enables client side request weight accounting.
+    /// Limits start from the market defaults and can be synced from `exchangeInfo`.
+    pub fn rate_limiter(mut self, mode: RateLimitMode) -> Self {
+        self.rate_limit_mode = Some(mode);
+        self
+    }
+
+    /// Enables client side accounting of the orders placed by the account.
+    /// Limits start from the market defaults and can be synced from `exchangeInfo`.
+    pub fn order_rate_limiter(mut self, mode: RateLimitMode) -> Self {
+        self.order_rate_limit_mode = Some(mode);
+        self
+    }
+
+    /// Retries failed requests according to the policy. By default nothing is retried.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Sends requests through a custom transport instead of the default reqwest client.
+    /// The HTTP options of this builder (timeouts, `tcp_nodelay`, HTTP/2, pool, local address) are ignored then.
+    pub fn transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Adds an interceptor to the chain, interceptors run in the order they were added.
+    pub fn interceptor(mut self, interceptor: Arc<dyn Interceptor>) -> Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+
+    /// Reports every request to the sink, e.g. a shared `MetricsRegistry`.
+    pub fn metrics(mut self, sink: Arc<dyn MetricsSink>) -> Self {
+        self.metrics = Some(sink);
+        self
+    }
+
+    /// Keeps the body of every response in `Response::raw`, e.g. for audit logs.
+    pub fn keep_raw_body(mut self, keep: bool) -> Self {
+        self.keep_raw_body = keep;
+        self
+    }
+
+    /// Decodes responses with `backend` instead of the fastest one compiled in.
+    pub fn json_backend(mut self, backend: JsonBackend) -> Self {
+        self.json_backend = backend;
+        self
+    }
+
+    /// Decodes responses with a custom decoder, in place of any `json_backend`.
+    pub fn json_decoder(mut self, decoder: Arc<dyn DynJsonDecoder>) -> Self {
+        self.json_decoder = Some(decoder);
+        self
+    }
+
     fn to_client_builder(self, host: String) -> BinanceClientBuilder {
         BinanceClientBuilder {
             host,
+            fallback_hosts: self.fallback_hosts,
+            host_selection: self.host_selection,
+            host_health_check: self.host_health_check_interval.map(|interval| (C::PING_PATH, interval)),
             api_key: self.api_key,
-            secret_key: self.secret_key,
+            signing_key: self.signing_key,
             recv_window: self.recv_window,
+            time_unit: self.time_unit,
             http_connect_timeout_ms: self.http_connect_timeout_ms,
             http_request_timeout_ms: self.http_request_timeout_ms,
             tcp_nodelay: self.tcp_nodelay,
+            http2_prior_knowledge: self.http2_prior_knowledge,
+            pool_idle_timeout: self.pool_idle_timeout,
+            pool_max_idle_per_host: self.pool_max_idle_per_host,
+            warm_up: match (self.warm_up_connections, self.keep_alive_interval) {
+                (Some(connections), _) => Some((C::PING_PATH, connections)),
+                (None, Some(_)) => Some((C::PING_PATH, 1)),
+                (None, None) => None,
+            },
+            keep_alive_interval: self.keep_alive_interval,
             local_addr: self.local_addr,
+            local_addrs: self.local_addrs,
+            proxy: self.proxy,
+            server_time_sync: self
+                .server_time_sync_interval
+                .map(|interval| (C::SERVER_TIME_PATH, interval)),
+            clock: self.clock,
+            rate_limiter: self.rate_limit_mode.map(|mode| {
+                RateLimiter::new(mode, &[WeightLimit::per_minute(C::REQUEST_WEIGHT_LIMIT_1M)])
+            }),
+            order_limiter: self
+                .order_rate_limit_mode
+                .map(|mode| RateLimiter::orders(mode, C::ORDER_LIMITS)),
+            retry_policy: self.retry_policy,
+            transport: self.transport,
+            interceptors: self.interceptors,
+            metrics: self.metrics,
+            keep_raw_body: self.keep_raw_body,
+            json_backend: self.json_backend,
+            json_decoder: self.json_decoder,
         }
     }
 
-    pub fn build(self) -> Result<C, BinanceError<()>> {
-        let client = self.to_client_builder(C::HOST.to_owned());
-        Ok(C::from_client(client.build()?))
+    pub fn build(mut self) -> Result<C, BinanceError<()>> {
+        let host = self.host.take().unwrap_or_else(|| C::HOST.to_owned());
+        let client = self.to_client_builder(host);
+        C::build(client)
     }
 }
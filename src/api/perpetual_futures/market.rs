@@ -1,8 +1,9 @@
 use crate::api::client::error::BinanceError;
+use crate::api::client::options::RequestOptions;
 use crate::api::client::response::Response;
 use crate::api::perpetual_futures::BinancePerpFutures;
 
-use crate::schemes::error::Error;
+use crate::schemes::error::FuturesError;
 use crate::schemes::common::{Pong, ServerTime};
 use crate::schemes::futures_fapi::general::ExchangeInfo;
 use crate::schemes::futures_fapi::market::*;
@@ -24,30 +25,54 @@ impl BinancePerpFutures {
     /// Test connectivity to the Rest API.
     ///
     /// Weight(IP): 1
-    pub async fn ping(&self) -> Result<Response<Pong>, BinanceError<Error>> {
+    pub async fn ping(&self) -> Result<Response<Pong>, BinanceError<FuturesError>> {
+        self.ping_with(&RequestOptions::default()).await
+    }
+
+    /// [`ping`](Self::ping) with options for this call.
+    pub async fn ping_with(
+        &self,
+        opts: &RequestOptions,
+    ) -> Result<Response<Pong>, BinanceError<FuturesError>> {
         self
             .client
-            .get::<_, _>(endpoints::FAPI_V1_PING)
+            .get::<_, _>(endpoints::FAPI_V1_PING, 1, opts)
             .await
     }
 
     /// Test connectivity to the Rest API and get the current server time.
     ///
     /// Weight(IP): 1
-    pub async fn get_server_time(&self) -> Result<Response<ServerTime>, BinanceError<Error>> {
+    pub async fn get_server_time(&self) -> Result<Response<ServerTime>, BinanceError<FuturesError>> {
+        self.get_server_time_with(&RequestOptions::default()).await
+    }
+
+    /// [`get_server_time`](Self::get_server_time) with options for this call.
+    pub async fn get_server_time_with(
+        &self,
+        opts: &RequestOptions,
+    ) -> Result<Response<ServerTime>, BinanceError<FuturesError>> {
         self
             .client
-            .get::<_, _>(endpoints::FAPI_V1_TIME)
+            .get::<_, _>(endpoints::FAPI_V1_TIME, 1, opts)
             .await
     }
 
     /// Current exchange trading rules and symbol information
     ///
     /// Weight(IP): 10
-    pub async fn get_exchange_info(&self) -> Result<Response<ExchangeInfo>, BinanceError<Error>> {
+    pub async fn get_exchange_info(&self) -> Result<Response<ExchangeInfo>, BinanceError<FuturesError>> {
+        self.get_exchange_info_with(&RequestOptions::default()).await
+    }
+
+    /// [`get_exchange_info`](Self::get_exchange_info) with options for this call.
+    pub async fn get_exchange_info_with(
+        &self,
+        opts: &RequestOptions,
+    ) -> Result<Response<ExchangeInfo>, BinanceError<FuturesError>> {
         self
             .client
-            .get::<_, _>(endpoints::FAPI_V1_EXCHANGE_INFO)
+            .get::<_, _>(endpoints::FAPI_V1_EXCHANGE_INFO, 1, opts)
             .await
     }
 
@@ -69,9 +94,20 @@ impl BinancePerpFutures {
         &self,
         symbol: &str,
         limit: DepthLimit,
-    ) -> Result<Response<OrderBook>, BinanceError<Error>> {
+    ) -> Result<Response<OrderBook>, BinanceError<FuturesError>> {
+        self.get_depth_with(symbol, limit, &RequestOptions::default()).await
+    }
+
+    /// [`get_depth`](Self::get_depth) with options for this call.
+    pub async fn get_depth_with(
+        &self,
+        symbol: &str,
+        limit: DepthLimit,
+        opts: &RequestOptions,
+    ) -> Result<Response<OrderBook>, BinanceError<FuturesError>> {
+        let weight = u8::from(limit.request_weight()).into();
         self.client
-            .get_with_query::<_, _>(endpoints::FAPI_V1_DEPTH, 34, |url| {
+            .get_with_query::<_, _>(endpoints::FAPI_V1_DEPTH, weight, opts, 34, |url| {
                 url.add_param_str("symbol", symbol);
                 url.add_param_integer("limit", u16::from(limit));
             })
@@ -1,6 +1,8 @@
 use crate::api::client::error::BinanceError;
+use crate::api::client::options::RequestOptions;
 use crate::api::client::response::Response;
 use crate::api::spot::BinanceSpot;
+use bytes::Bytes;
 
 use crate::schemes::error::Error;
 use crate::schemes::common::{Pong, ServerTime};
@@ -29,14 +31,34 @@ pub mod endpoints {
     pub static API_V3_TICKER_BOOK_TICKER: &str = "/api/v3/ticker/bookTicker";
 }
 
+/// Weight of the 24hr ticker statistics without the `symbols` parameter.
+const TICKERS_24H_ALL_WEIGHT: u32 = 80;
+
+/// Weight of the 24hr ticker statistics for a JSON array of symbols.
+fn tickers_24h_weight(symbols: &str) -> u32 {
+    match symbols.matches(',').count() + 1 {
+        1..=20 => 2,
+        21..=100 => 40,
+        _ => TICKERS_24H_ALL_WEIGHT,
+    }
+}
+
 impl BinanceSpot {
     /// Test connectivity to the Rest API.
     ///
     /// Weight(IP): 1
     pub async fn ping(&self) -> Result<Response<Pong>, BinanceError<Error>> {
+        self.ping_with(&RequestOptions::default()).await
+    }
+
+    /// [`ping`](Self::ping) with options for this call.
+    pub async fn ping_with(
+        &self,
+        opts: &RequestOptions,
+    ) -> Result<Response<Pong>, BinanceError<Error>> {
         self
             .client
-            .get::<_, _>(endpoints::API_V3_PING)
+            .get::<_, _>(endpoints::API_V3_PING, 1, opts)
             .await
     }
 
@@ -44,9 +66,17 @@ impl BinanceSpot {
     ///
     /// Weight(IP): 1
     pub async fn get_server_time(&self) -> Result<Response<ServerTime>, BinanceError<Error>> {
+        self.get_server_time_with(&RequestOptions::default()).await
+    }
+
+    /// [`get_server_time`](Self::get_server_time) with options for this call.
+    pub async fn get_server_time_with(
+        &self,
+        opts: &RequestOptions,
+    ) -> Result<Response<ServerTime>, BinanceError<Error>> {
         self
             .client
-            .get::<_, _>(endpoints::API_V3_TIME)
+            .get::<_, _>(endpoints::API_V3_TIME, 1, opts)
             .await
     }
 
@@ -54,9 +84,17 @@ impl BinanceSpot {
     ///
     /// Weight(IP): 10
     pub async fn get_exchange_info(&self) -> Result<Response<ExchangeInfo>, BinanceError<Error>> {
+        self.get_exchange_info_with(&RequestOptions::default()).await
+    }
+
+    /// [`get_exchange_info`](Self::get_exchange_info) with options for this call.
+    pub async fn get_exchange_info_with(
+        &self,
+        opts: &RequestOptions,
+    ) -> Result<Response<ExchangeInfo>, BinanceError<Error>> {
         self
             .client
-            .get::<_, _>(endpoints::API_V3_EXCHANGE_INFO)
+            .get::<_, _>(endpoints::API_V3_EXCHANGE_INFO, 10, opts)
             .await
     }
 
@@ -79,8 +117,35 @@ impl BinanceSpot {
         symbol: &str,
         limit: DepthLimit,
     ) -> Result<Response<OrderBook>, BinanceError<Error>> {
+        self.get_depth_with(symbol, limit, &RequestOptions::default()).await
+    }
+
+    /// [`get_depth`](Self::get_depth) with options for this call.
+    pub async fn get_depth_with(
+        &self,
+        symbol: &str,
+        limit: DepthLimit,
+        opts: &RequestOptions,
+    ) -> Result<Response<OrderBook>, BinanceError<Error>> {
+        let weight = u8::from(limit.request_weight()).into();
         self.client
-            .get_with_query::<_, _>(endpoints::API_V3_DEPTH, 40, |url| {
+            .get_with_query::<_, _>(endpoints::API_V3_DEPTH, weight, opts, 40, |url| {
+                url.add_param_str("symbol", symbol);
+                url.add_param_integer("limit", u16::from(limit));
+            })
+            .await
+    }
+
+    /// [`get_depth`](Self::get_depth) with the body left undecoded,
+    /// e.g. for [`OrderBookRef`] with [`Response::parse`].
+    pub async fn get_depth_raw(
+        &self,
+        symbol: &str,
+        limit: DepthLimit,
+    ) -> Result<Response<Bytes>, BinanceError<Error>> {
+        let weight = u8::from(limit.request_weight()).into();
+        self.client
+            .get_with_query_raw(endpoints::API_V3_DEPTH, weight, &RequestOptions::default(), 40, |url| {
                 url.add_param_str("symbol", symbol);
                 url.add_param_integer("limit", u16::from(limit));
             })
@@ -94,9 +159,36 @@ impl BinanceSpot {
         &self,
         symbol: &str,
         limit: Option<u16>,
+    ) -> Result<Response<Vec<Trade>>, BinanceError<Error>> {
+        self.get_recent_trades_with(symbol, limit, &RequestOptions::default()).await
+    }
+
+    /// [`get_recent_trades`](Self::get_recent_trades) with options for this call.
+    pub async fn get_recent_trades_with(
+        &self,
+        symbol: &str,
+        limit: Option<u16>,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<Trade>>, BinanceError<Error>> {
         self.client
-            .get_with_query::<_, _>(endpoints::API_V3_TRADES, 40, |url| {
+            .get_with_query::<_, _>(endpoints::API_V3_TRADES, 1, opts, 40, |url| {
+                url.add_param_str("symbol", symbol);
+                if let Some(l) = limit {
+                    url.add_param_integer("limit", l);
+                }
+            })
+            .await
+    }
+
+    /// [`get_recent_trades`](Self::get_recent_trades) with the body left undecoded,
+    /// e.g. for `Vec<`[`TradeRef`]`>` with [`Response::parse`].
+    pub async fn get_recent_trades_raw(
+        &self,
+        symbol: &str,
+        limit: Option<u16>,
+    ) -> Result<Response<Bytes>, BinanceError<Error>> {
+        self.client
+            .get_with_query_raw(endpoints::API_V3_TRADES, 1, &RequestOptions::default(), 40, |url| {
                 url.add_param_str("symbol", symbol);
                 if let Some(l) = limit {
                     url.add_param_integer("limit", l);
@@ -113,10 +205,23 @@ impl BinanceSpot {
         symbol: &str,
         from_id: Option<u64>,
         limit: Option<u16>,
+    ) -> Result<Response<Vec<Trade>>, BinanceError<Error>> {
+        self.get_historical_trades_with(symbol, from_id, limit, &RequestOptions::default()).await
+    }
+
+    /// [`get_historical_trades`](Self::get_historical_trades) with options for this call.
+    pub async fn get_historical_trades_with(
+        &self,
+        symbol: &str,
+        from_id: Option<u64>,
+        limit: Option<u16>,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<Trade>>, BinanceError<Error>> {
         self.client
             .get_with_query_api_key::<_, _>(
                 endpoints::API_V3_HISTORICAL_TRADES,
+                5,
+                opts,
                 70,
                 |url| {
                     url.add_param_str("symbol", symbol);
@@ -142,9 +247,22 @@ impl BinanceSpot {
         start_time: Option<u64>,
         end_time: Option<u64>,
         limit: Option<u16>,
+    ) -> Result<Response<Vec<AggregateTrade>>, BinanceError<Error>> {
+        self.get_aggregate_trades_with(symbol, from_id, start_time, end_time, limit, &RequestOptions::default()).await
+    }
+
+    /// [`get_aggregate_trades`](Self::get_aggregate_trades) with options for this call.
+    pub async fn get_aggregate_trades_with(
+        &self,
+        symbol: &str,
+        from_id: Option<u64>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u16>,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<AggregateTrade>>, BinanceError<Error>> {
         self.client
-            .get_with_query::<_, _>(endpoints::API_V3_AGG_TRADES, 128, |url| {
+            .get_with_query::<_, _>(endpoints::API_V3_AGG_TRADES, 1, opts, 128, |url| {
                 url.add_param_str("symbol", symbol);
 
                 if let Some(id) = from_id {
@@ -176,9 +294,22 @@ impl BinanceSpot {
         start_time: Option<u64>,
         end_time: Option<u64>,
         limit: Option<u16>,
+    ) -> Result<Response<Vec<KlineSummary>>, BinanceError<Error>> {
+        self.get_klines_with(symbol, interval, start_time, end_time, limit, &RequestOptions::default()).await
+    }
+
+    /// [`get_klines`](Self::get_klines) with options for this call.
+    pub async fn get_klines_with(
+        &self,
+        symbol: &str,
+        interval: KLinesInterval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u16>,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<KlineSummary>>, BinanceError<Error>> {
         self.client
-            .get_with_query::<_, _>(endpoints::API_V3_KLINES, 128, |url| {
+            .get_with_query::<_, _>(endpoints::API_V3_KLINES, 1, opts, 128, |url| {
                 url.add_param_str("symbol", symbol);
                 url.add_param_str("interval", interval.as_str());
 
@@ -208,9 +339,22 @@ impl BinanceSpot {
         start_time: Option<u64>,
         end_time: Option<u64>,
         limit: Option<u16>,
+    ) -> Result<Response<Vec<KlineSummary>>, BinanceError<Error>> {
+        self.get_klines_ui_with(symbol, interval, start_time, end_time, limit, &RequestOptions::default()).await
+    }
+
+    /// [`get_klines_ui`](Self::get_klines_ui) with options for this call.
+    pub async fn get_klines_ui_with(
+        &self,
+        symbol: &str,
+        interval: KLinesInterval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u16>,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<KlineSummary>>, BinanceError<Error>> {
         self.client
-            .get_with_query::<_, _>(endpoints::API_V3_UI_KLINES, 128, |url| {
+            .get_with_query::<_, _>(endpoints::API_V3_UI_KLINES, 1, opts, 128, |url| {
                 url.add_param_str("symbol", symbol);
                 url.add_param_str("interval", interval.as_str());
 
@@ -233,9 +377,18 @@ impl BinanceSpot {
     pub async fn get_average_price(
         &self,
         symbol: &str,
+    ) -> Result<Response<AveragePrice>, BinanceError<Error>> {
+        self.get_average_price_with(symbol, &RequestOptions::default()).await
+    }
+
+    /// [`get_average_price`](Self::get_average_price) with options for this call.
+    pub async fn get_average_price_with(
+        &self,
+        symbol: &str,
+        opts: &RequestOptions,
     ) -> Result<Response<AveragePrice>, BinanceError<Error>> {
         self.client
-            .get_with_query::<_, _>(endpoints::API_V3_AVG_PRICE, 32, |url| {
+            .get_with_query::<_, _>(endpoints::API_V3_AVG_PRICE, 1, opts, 32, |url| {
                 url.add_param_str("symbol", symbol);
             })
             .await
@@ -247,9 +400,18 @@ impl BinanceSpot {
     pub async fn get_24h_ticker_full(
         &self,
         symbol: &str,
+    ) -> Result<Response<TickerStatsFull>, BinanceError<Error>> {
+        self.get_24h_ticker_full_with(symbol, &RequestOptions::default()).await
+    }
+
+    /// [`get_24h_ticker_full`](Self::get_24h_ticker_full) with options for this call.
+    pub async fn get_24h_ticker_full_with(
+        &self,
+        symbol: &str,
+        opts: &RequestOptions,
     ) -> Result<Response<TickerStatsFull>, BinanceError<Error>> {
         self.client
-            .get_with_query::<_, _>(endpoints::API_V3_TICKER_24H, 40, |url| {
+            .get_with_query::<_, _>(endpoints::API_V3_TICKER_24H, 1, opts, 40, |url| {
                 url.add_param_str("symbol", symbol);
                 url.add_param_str("type", "FULL");
             })
@@ -262,9 +424,18 @@ impl BinanceSpot {
     pub async fn get_24h_ticker_mini(
         &self,
         symbol: &str,
+    ) -> Result<Response<TickerStatsMini>, BinanceError<Error>> {
+        self.get_24h_ticker_mini_with(symbol, &RequestOptions::default()).await
+    }
+
+    /// [`get_24h_ticker_mini`](Self::get_24h_ticker_mini) with options for this call.
+    pub async fn get_24h_ticker_mini_with(
+        &self,
+        symbol: &str,
+        opts: &RequestOptions,
     ) -> Result<Response<TickerStatsMini>, BinanceError<Error>> {
         self.client
-            .get_with_query::<_, _>(endpoints::API_V3_TICKER_24H, 40, |url| {
+            .get_with_query::<_, _>(endpoints::API_V3_TICKER_24H, 1, opts, 40, |url| {
                 url.add_param_str("symbol", symbol);
                 url.add_param_str("type", "MINI");
             })
@@ -300,12 +471,22 @@ impl BinanceSpot {
     pub async fn get_24h_tickers_full(
         &self,
         symbols: Option<&str>,
+    ) -> Result<Response<Vec<TickerStatsFull>>, BinanceError<Error>> {
+        self.get_24h_tickers_full_with(symbols, &RequestOptions::default()).await
+    }
+
+    /// [`get_24h_tickers_full`](Self::get_24h_tickers_full) with options for this call.
+    pub async fn get_24h_tickers_full_with(
+        &self,
+        symbols: Option<&str>,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<TickerStatsFull>>, BinanceError<Error>> {
         if let Some(val) = symbols {
             let query_len = val.len() + 32;
+            let weight = tickers_24h_weight(val);
             return self
                 .client
-                .get_with_query::<_, _>(endpoints::API_V3_TICKER_24H, query_len, |url| {
+                .get_with_query::<_, _>(endpoints::API_V3_TICKER_24H, weight, opts, query_len, |url| {
                     url.add_param_str("type", "FULL");
                     url.add_param_str("symbols", val);
                 })
@@ -313,7 +494,7 @@ impl BinanceSpot {
         }
 
         self.client
-            .get_with_query::<_, _>(endpoints::API_V3_TICKER_24H, 10, |url| {
+            .get_with_query::<_, _>(endpoints::API_V3_TICKER_24H, TICKERS_24H_ALL_WEIGHT, opts, 10, |url| {
                 url.add_param_str("type", "FULL");
             })
             .await
@@ -324,12 +505,22 @@ impl BinanceSpot {
     pub async fn get_24h_tickers_mini(
         &self,
         symbols: Option<&str>,
+    ) -> Result<Response<Vec<TickerStatsMini>>, BinanceError<Error>> {
+        self.get_24h_tickers_mini_with(symbols, &RequestOptions::default()).await
+    }
+
+    /// [`get_24h_tickers_mini`](Self::get_24h_tickers_mini) with options for this call.
+    pub async fn get_24h_tickers_mini_with(
+        &self,
+        symbols: Option<&str>,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<TickerStatsMini>>, BinanceError<Error>> {
         if let Some(val) = symbols {
             let query_len = val.len() + 32;
+            let weight = tickers_24h_weight(val);
             return self
                 .client
-                .get_with_query::<_, _>(endpoints::API_V3_TICKER_24H, query_len, |url| {
+                .get_with_query::<_, _>(endpoints::API_V3_TICKER_24H, weight, opts, query_len, |url| {
                     url.add_param_str("type", "MINI");
                     url.add_param_str("symbols", val);
                 }).await;
@@ -337,7 +528,7 @@ impl BinanceSpot {
 
         self
             .client
-            .get_with_query::<_, _>(endpoints::API_V3_TICKER_24H, 10, |url| {
+            .get_with_query::<_, _>(endpoints::API_V3_TICKER_24H, TICKERS_24H_ALL_WEIGHT, opts, 10, |url| {
                 url.add_param_str("type", "MINI");
             })
             .await
@@ -349,9 +540,18 @@ impl BinanceSpot {
     pub async fn get_latest_price(
         &self,
         symbol: &str,
+    ) -> Result<Response<SymbolPrice>, BinanceError<Error>> {
+        self.get_latest_price_with(symbol, &RequestOptions::default()).await
+    }
+
+    /// [`get_latest_price`](Self::get_latest_price) with options for this call.
+    pub async fn get_latest_price_with(
+        &self,
+        symbol: &str,
+        opts: &RequestOptions,
     ) -> Result<Response<SymbolPrice>, BinanceError<Error>> {
         self.client
-            .get_with_query::<_, _>(endpoints::API_V3_TICKER_PRICE, 32, |url| {
+            .get_with_query::<_, _>(endpoints::API_V3_TICKER_PRICE, 1, opts, 32, |url| {
                 url.add_param_str("symbol", symbol);
             })
             .await
@@ -363,19 +563,28 @@ impl BinanceSpot {
     pub async fn get_latest_prices(
         &self,
         symbols: Option<&str>,
+    ) -> Result<Response<Vec<SymbolPrice>>, BinanceError<Error>> {
+        self.get_latest_prices_with(symbols, &RequestOptions::default()).await
+    }
+
+    /// [`get_latest_prices`](Self::get_latest_prices) with options for this call.
+    pub async fn get_latest_prices_with(
+        &self,
+        symbols: Option<&str>,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<SymbolPrice>>, BinanceError<Error>> {
         if let Some(val) = symbols {
             let query_len = val.len() + 16;
             return self
                 .client
-                .get_with_query::<_, _>(endpoints::API_V3_TICKER_PRICE, query_len, |url| {
+                .get_with_query::<_, _>(endpoints::API_V3_TICKER_PRICE, 2, opts, query_len, |url| {
                     url.add_param_str("symbols", val);
                 })
                 .await;
         }
 
         self.client
-            .get::<_, _>(endpoints::API_V3_TICKER_PRICE)
+            .get::<_, _>(endpoints::API_V3_TICKER_PRICE, 2, opts)
             .await
     }
 
@@ -385,9 +594,31 @@ impl BinanceSpot {
     pub async fn get_book_ticker(
         &self,
         symbol: &str,
+    ) -> Result<Response<BookTicker>, BinanceError<Error>> {
+        self.get_book_ticker_with(symbol, &RequestOptions::default()).await
+    }
+
+    /// [`get_book_ticker`](Self::get_book_ticker) with options for this call.
+    pub async fn get_book_ticker_with(
+        &self,
+        symbol: &str,
+        opts: &RequestOptions,
     ) -> Result<Response<BookTicker>, BinanceError<Error>> {
         self.client
-            .get_with_query::<_, _>(endpoints::API_V3_TICKER_BOOK_TICKER, 32, |url| {
+            .get_with_query::<_, _>(endpoints::API_V3_TICKER_BOOK_TICKER, 1, opts, 32, |url| {
+                url.add_param_str("symbol", symbol);
+            })
+            .await
+    }
+
+    /// [`get_book_ticker`](Self::get_book_ticker) with the body left undecoded,
+    /// e.g. for [`BookTickerRef`] with [`Response::parse`].
+    pub async fn get_book_ticker_raw(
+        &self,
+        symbol: &str,
+    ) -> Result<Response<Bytes>, BinanceError<Error>> {
+        self.client
+            .get_with_query_raw(endpoints::API_V3_TICKER_BOOK_TICKER, 1, &RequestOptions::default(), 32, |url| {
                 url.add_param_str("symbol", symbol);
             })
             .await
@@ -400,18 +631,48 @@ impl BinanceSpot {
         &self,
         symbols: Option<&str>,
     ) -> Result<Response<Vec<BookTicker>>, BinanceError<Error>> {
+        self.get_book_tickers_with(symbols, &RequestOptions::default()).await
+    }
+
+    /// [`get_book_tickers`](Self::get_book_tickers) with options for this call.
+    pub async fn get_book_tickers_with(
+        &self,
+        symbols: Option<&str>,
+        opts: &RequestOptions,
+    ) -> Result<Response<Vec<BookTicker>>, BinanceError<Error>> {
+        if let Some(val) = symbols {
+            let query_len = val.len() + 16;
+            return self
+                .client
+                .get_with_query::<_, _>(endpoints::API_V3_TICKER_BOOK_TICKER, 2, opts, query_len, |url| {
+                    url.add_param_str("symbols", val);
+                })
+                .await;
+        }
+
+        self.client
+            .get::<_, _>(endpoints::API_V3_TICKER_BOOK_TICKER, 2, opts)
+            .await
+    }
+
+    /// [`get_book_tickers`](Self::get_book_tickers) with the body left undecoded,
+    /// e.g. for `Vec<`[`BookTickerRef`]`>` with [`Response::parse`].
+    pub async fn get_book_tickers_raw(
+        &self,
+        symbols: Option<&str>,
+    ) -> Result<Response<Bytes>, BinanceError<Error>> {
         if let Some(val) = symbols {
             let query_len = val.len() + 16;
             return self
                 .client
-                .get_with_query::<_, _>(endpoints::API_V3_TICKER_BOOK_TICKER, query_len, |url| {
+                .get_with_query_raw(endpoints::API_V3_TICKER_BOOK_TICKER, 2, &RequestOptions::default(), query_len, |url| {
                     url.add_param_str("symbols", val);
                 })
                 .await;
         }
 
         self.client
-            .get::<_, _>(endpoints::API_V3_TICKER_BOOK_TICKER)
+            .get_raw(endpoints::API_V3_TICKER_BOOK_TICKER, 2, &RequestOptions::default())
             .await
     }
 }
@@ -1,11 +1,28 @@
 pub mod market;
 pub mod trade;
 
+use crate::api::client::credentials::SecretString;
 use crate::api::client::error::BinanceError;
+use crate::api::client::hosts::HostPool;
+use crate::api::client::proxy::ProxyConfig;
+use crate::api::client::rate_limit::{RateLimiter, WeightLimit};
+use crate::api::client::source_ip::SourceIpPool;
+use crate::api::client::time_sync::TimeOffset;
 use crate::api::client::BinanceClient;
 use crate::api::{BinanceBuilder, FromBinanceClient};
+use crate::schemes::error::Error;
+use crate::types::time::TimeUnit;
 
 pub const SPOT_API: &'static str = "https://api.binance.com";
+/// `api.binance.com` and its alternates, for `BinanceBuilder::hosts`.
+pub const SPOT_API_CLUSTERS: [&str; 6] = [
+    SPOT_API,
+    "https://api-gcp.binance.com",
+    "https://api1.binance.com",
+    "https://api2.binance.com",
+    "https://api3.binance.com",
+    "https://api4.binance.com",
+];
 pub const SPOT_TESTNET_API: &'static str = "https://testnet.binance.vision";
 
 #[derive(Clone)]
@@ -26,8 +43,8 @@ impl BinanceSpot {
 
     pub fn signed<K1, K2>(api_key: K1, secret_key: K2) -> Result<Self, BinanceError<()>>
     where
-        K1: Into<String>,
-        K2: Into<String>,
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
     {
         Ok(Self {
             client: BinanceClient::signed(SPOT_API.to_owned(), api_key.into(), secret_key.into())?,
@@ -36,8 +53,8 @@ impl BinanceSpot {
 
     pub fn testnet<K1, K2>(api_key: K1, secret_key: K2) -> Result<Self, BinanceError<()>>
     where
-        K1: Into<String>,
-        K2: Into<String>,
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
     {
         Ok(Self {
             client: BinanceClient::signed(
@@ -61,19 +78,102 @@ impl BinanceSpot {
     ) -> Result<Self, BinanceError<()>>
     where
         S: Into<String>,
-        K1: Into<String>,
-        K2: Into<String>,
+        K1: Into<SecretString>,
+        K2: Into<SecretString>,
     {
         Ok(Self {
             client: BinanceClient::signed(host.into(), api_key.into(), secret_key.into())?,
         })
     }
+
+    /// Measures the offset between the local and the server clock.
+    /// The offset is applied to the timestamp of every following signed request.
+    pub async fn sync_server_time(&self) -> Result<TimeOffset, BinanceError<Error>> {
+        self.client
+            .sync_server_time(market::endpoints::API_V3_TIME)
+            .await
+    }
+
+    /// Last measured server clock offset and round-trip time.
+    pub fn time_offset(&self) -> TimeOffset {
+        self.client.time_offset()
+    }
+
+    /// Loads the `REQUEST_WEIGHT` and `ORDERS` limits of the client side rate limiters from `exchangeInfo`.
+    pub async fn sync_rate_limits(&self) -> Result<(), BinanceError<Error>> {
+        let resp = self.get_exchange_info().await?;
+        self.client.set_exchange_limits(&resp.payload.rate_limits);
+        Ok(())
+    }
+
+    /// Client side weight accounting, if enabled with `BinanceBuilder::rate_limiter`.
+    /// With `BinanceBuilder::bind_local_addresses` the weight is kept per address instead.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.client.rate_limiter()
+    }
+
+    /// Order count of the account, if enabled with `BinanceBuilder::order_rate_limiter`.
+    pub fn order_rate_limiter(&self) -> Option<&RateLimiter> {
+        self.client.order_rate_limiter()
+    }
+
+    /// Local addresses set with `BinanceBuilder::bind_local_addresses` and their weight accounting.
+    pub fn source_ip_pool(&self) -> Option<&SourceIpPool> {
+        self.client.source_ip_pool()
+    }
+
+    /// Proxy set with `BinanceBuilder::proxy`.
+    pub fn proxy(&self) -> Option<&ProxyConfig> {
+        self.client.proxy()
+    }
+
+    /// Pings every host set with `BinanceBuilder::hosts` and updates its health and latency.
+    pub async fn check_hosts(&self) {
+        self.client.check_hosts(market::endpoints::API_V3_PING).await
+    }
+
+    /// Opens up to `connections` pooled connections to the current host with concurrent pings,
+    /// see `BinanceBuilder::warm_up_connections`. Returns how many pings succeeded.
+    pub async fn warm_up(&self, connections: usize) -> usize {
+        self.client.warm_up(market::endpoints::API_V3_PING, connections).await
+    }
+
+    /// Hosts of the client, if several are set with `BinanceBuilder::hosts`.
+    pub fn host_pool(&self) -> Option<&HostPool> {
+        self.client.host_pool()
+    }
+
+    /// Resolution of the response timestamps, set with `BinanceBuilder::time_unit`.
+    pub fn time_unit(&self) -> TimeUnit {
+        self.client.time_unit()
+    }
+}
+
+impl BinanceBuilder<BinanceSpot> {
+    /// Sends `X-MBX-TIME-UNIT` to get the response timestamps in microseconds
+    /// and sends the `timestamp` of signed requests in microseconds as well.
+    /// `recvWindow` stays in milliseconds.
+    pub fn time_unit(mut self, time_unit: TimeUnit) -> Self {
+        self.time_unit = time_unit;
+        self
+    }
 }
 
 impl FromBinanceClient for BinanceSpot {
     const HOST: &'static str = SPOT_API;
+    const SERVER_TIME_PATH: &'static str = market::endpoints::API_V3_TIME;
+    const PING_PATH: &'static str = market::endpoints::API_V3_PING;
+    const REQUEST_WEIGHT_LIMIT_1M: u32 = 6000;
+    const ORDER_LIMITS: &'static [WeightLimit] = &[
+        WeightLimit { interval_ms: 10_000, limit: 50 },
+        WeightLimit { interval_ms: 86_400_000, limit: 160_000 },
+    ];
 
     fn from_client(client: BinanceClient) -> Self {
         Self { client }
     }
+
+    fn client(&self) -> &BinanceClient {
+        &self.client
+    }
 }
@@ -1,4 +1,5 @@
 use crate::api::client::error::BinanceError;
+use crate::api::client::options::RequestOptions;
 use crate::api::client::response::Response;
 use crate::api::spot::BinanceSpot;
 
@@ -36,9 +37,18 @@ impl BinanceSpot {
     pub async fn test_new_order(
         &self,
         req: &NewOrderReq,
+    ) -> Result<Response<TestNewOrderRes>, BinanceError<Error>> {
+        self.test_new_order_with(req, &RequestOptions::default()).await
+    }
+
+    /// [`test_new_order`](Self::test_new_order) with options for this call.
+    pub async fn test_new_order_with(
+        &self,
+        req: &NewOrderReq,
+        opts: &RequestOptions,
     ) -> Result<Response<TestNewOrderRes>, BinanceError<Error>> {
         self.client
-            .post_signed_with_query::<_, _>(endpoints::API_V3_ORDER_TEST, 128, |url| {
+            .post_signed_with_body::<_, _>(endpoints::API_V3_ORDER_TEST, 1, opts, 128, |url| {
                 url.add_params_from_data(req)?;
                 Ok(())
             })
@@ -54,8 +64,18 @@ impl BinanceSpot {
         &self,
         req: &NewOrderReq,
     ) -> Result<Response<NewOrderRes>, BinanceError<Error>> {
+        self.new_order_with(req, &RequestOptions::default()).await
+    }
+
+    /// [`new_order`](Self::new_order) with options for this call.
+    pub async fn new_order_with(
+        &self,
+        req: &NewOrderReq,
+        opts: &RequestOptions,
+    ) -> Result<Response<NewOrderRes>, BinanceError<Error>> {
+        self.client.acquire_orders_with(1, opts).await?;
         self.client
-            .post_signed_with_query::<_, _>(endpoints::API_V3_ORDER, 128, |url| {
+            .post_signed_with_body::<_, _>(endpoints::API_V3_ORDER, 1, opts, 128, |url| {
                 url.add_params_from_data(req)?;
                 Ok(())
             })
@@ -71,9 +91,18 @@ impl BinanceSpot {
     pub async fn cancel_order(
         &self,
         req: &CancelOrderReq,
+    ) -> Result<Response<CancelOrderRes>, BinanceError<Error>> {
+        self.cancel_order_with(req, &RequestOptions::default()).await
+    }
+
+    /// [`cancel_order`](Self::cancel_order) with options for this call.
+    pub async fn cancel_order_with(
+        &self,
+        req: &CancelOrderReq,
+        opts: &RequestOptions,
     ) -> Result<Response<CancelOrderRes>, BinanceError<Error>> {
         self.client
-            .delete_signed_with_query::<_, _>(endpoints::API_V3_ORDER, 128, |url| {
+            .delete_signed_with_body::<_, _>(endpoints::API_V3_ORDER, 1, opts, 128, |url| {
                 url.add_params_from_data(req)?;
                 Ok(())
             })
@@ -88,9 +117,18 @@ impl BinanceSpot {
     pub async fn cancel_all_orders(
         &self,
         symbol: &str,
+    ) -> Result<Response<Vec<CancelOrderRes>>, BinanceError<Error>> {
+        self.cancel_all_orders_with(symbol, &RequestOptions::default()).await
+    }
+
+    /// [`cancel_all_orders`](Self::cancel_all_orders) with options for this call.
+    pub async fn cancel_all_orders_with(
+        &self,
+        symbol: &str,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<CancelOrderRes>>, BinanceError<Error>> {
         self.client
-            .delete_signed_with_query::<_, _>(endpoints::API_V3_OPEN_ORDERS, 32, |url| {
+            .delete_signed_with_body::<_, _>(endpoints::API_V3_OPEN_ORDERS, 1, opts, 32, |url| {
                 url.add_param_str("symbol", symbol);
                 Ok(())
             })
@@ -109,9 +147,20 @@ impl BinanceSpot {
         symbol: &str,
         order_id: Option<u64>,
         orig_client_order_id: Option<String>,
+    ) -> Result<Response<OrderInfo>, BinanceError<Error>> {
+        self.get_order_info_with(symbol, order_id, orig_client_order_id, &RequestOptions::default()).await
+    }
+
+    /// [`get_order_info`](Self::get_order_info) with options for this call.
+    pub async fn get_order_info_with(
+        &self,
+        symbol: &str,
+        order_id: Option<u64>,
+        orig_client_order_id: Option<String>,
+        opts: &RequestOptions,
     ) -> Result<Response<OrderInfo>, BinanceError<Error>> {
         self.client
-            .get_signed_with_query::<_, _>(endpoints::API_V3_ORDER, 128, |url| {
+            .get_signed_with_query::<_, _>(endpoints::API_V3_ORDER, 2, opts, 128, |url| {
                 url.add_param_str("symbol", symbol);
                 if let Some(id) = order_id {
                     url.add_param_integer("orderId", id);
@@ -135,8 +184,18 @@ impl BinanceSpot {
         &self,
         req: &CancelReplaceOrderReq,
     ) -> Result<Response<CancelReplaceOrderRes>, BinanceError<CancelReplaceOrderError>> {
+        self.cancel_replace_order_with(req, &RequestOptions::default()).await
+    }
+
+    /// [`cancel_replace_order`](Self::cancel_replace_order) with options for this call.
+    pub async fn cancel_replace_order_with(
+        &self,
+        req: &CancelReplaceOrderReq,
+        opts: &RequestOptions,
+    ) -> Result<Response<CancelReplaceOrderRes>, BinanceError<CancelReplaceOrderError>> {
+        self.client.acquire_orders_with(1, opts).await?;
         self.client
-            .post_signed_with_query::<_, _>(endpoints::API_V3_ORDER_CANCEL_REPLACE, 128, |url| {
+            .post_signed_with_body::<_, _>(endpoints::API_V3_ORDER_CANCEL_REPLACE, 1, opts, 128, |url| {
                 url.add_params_from_data(&req)?;
                 Ok(())
             })
@@ -150,9 +209,18 @@ impl BinanceSpot {
     pub async fn get_open_orders(
         &self,
         symbol: &str,
+    ) -> Result<Response<Vec<OrderInfo>>, BinanceError<Error>> {
+        self.get_open_orders_with(symbol, &RequestOptions::default()).await
+    }
+
+    /// [`get_open_orders`](Self::get_open_orders) with options for this call.
+    pub async fn get_open_orders_with(
+        &self,
+        symbol: &str,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<OrderInfo>>, BinanceError<Error>> {
         self.client
-            .get_signed_with_query::<_, _>(endpoints::API_V3_OPEN_ORDERS, 32, |url| {
+            .get_signed_with_query::<_, _>(endpoints::API_V3_OPEN_ORDERS, 3, opts, 32, |url| {
                 url.add_param_str("symbol", symbol);
                 Ok(())
             })
@@ -165,9 +233,17 @@ impl BinanceSpot {
     ///
     pub async fn get_all_open_orders(
         &self,
+    ) -> Result<Response<Vec<OrderInfo>>, BinanceError<Error>> {
+        self.get_all_open_orders_with(&RequestOptions::default()).await
+    }
+
+    /// [`get_all_open_orders`](Self::get_all_open_orders) with options for this call.
+    pub async fn get_all_open_orders_with(
+        &self,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<OrderInfo>>, BinanceError<Error>> {
         self.client
-            .get_signed::<_, _>(endpoints::API_V3_OPEN_ORDERS)
+            .get_signed::<_, _>(endpoints::API_V3_OPEN_ORDERS, 40, opts)
             .await
     }
 
@@ -179,8 +255,19 @@ impl BinanceSpot {
         &self,
         req: &NewOcoOrderReq,
     ) -> Result<Response<OcoOrderRes>, BinanceError<Error>> {
+        self.new_oco_order_with(req, &RequestOptions::default()).await
+    }
+
+    /// [`new_oco_order`](Self::new_oco_order) with options for this call.
+    pub async fn new_oco_order_with(
+        &self,
+        req: &NewOcoOrderReq,
+        opts: &RequestOptions,
+    ) -> Result<Response<OcoOrderRes>, BinanceError<Error>> {
+        // both legs count as orders
+        self.client.acquire_orders_with(2, opts).await?;
         self.client
-            .post_signed_with_query::<_, _>(endpoints::API_V3_ORDER_OCO, 256, |url| {
+            .post_signed_with_body::<_, _>(endpoints::API_V3_ORDER_OCO, 1, opts, 256, |url| {
                 url.add_params_from_data(req)?;
                 Ok(())
             })
@@ -200,9 +287,21 @@ impl BinanceSpot {
         order_list_id: Option<u64>,
         list_client_order_id: Option<String>,
         new_client_order_id: Option<String>,
+    ) -> Result<Response<OcoOrderRes>, BinanceError<Error>> {
+        self.cancel_oco_order_with(symbol, order_list_id, list_client_order_id, new_client_order_id, &RequestOptions::default()).await
+    }
+
+    /// [`cancel_oco_order`](Self::cancel_oco_order) with options for this call.
+    pub async fn cancel_oco_order_with(
+        &self,
+        symbol: &str,
+        order_list_id: Option<u64>,
+        list_client_order_id: Option<String>,
+        new_client_order_id: Option<String>,
+        opts: &RequestOptions,
     ) -> Result<Response<OcoOrderRes>, BinanceError<Error>> {
         self.client
-            .delete_signed_with_query::<_, _>(endpoints::API_V3_ORDER_LIST, 256, |url| {
+            .delete_signed_with_body::<_, _>(endpoints::API_V3_ORDER_LIST, 1, opts, 256, |url| {
                 url.add_param_str("symbol", symbol);
                 if let Some(id) = order_list_id {
                     url.add_param_integer("orderListId", id);
@@ -224,9 +323,18 @@ impl BinanceSpot {
     pub async fn get_oco_order(
         &self,
         id: &OrderIdOrClientOrderId,
+    ) -> Result<Response<OcoOrderRes>, BinanceError<Error>> {
+        self.get_oco_order_with(id, &RequestOptions::default()).await
+    }
+
+    /// [`get_oco_order`](Self::get_oco_order) with options for this call.
+    pub async fn get_oco_order_with(
+        &self,
+        id: &OrderIdOrClientOrderId,
+        opts: &RequestOptions,
     ) -> Result<Response<OcoOrderRes>, BinanceError<Error>> {
         self.client
-            .get_signed_with_query::<_, _>(endpoints::API_V3_ORDER_LIST, 256, |url| {
+            .get_signed_with_query::<_, _>(endpoints::API_V3_ORDER_LIST, 2, opts, 256, |url| {
                 match id {
                     OrderIdOrClientOrderId::OrderListId(id) => {
                         url.add_param_integer("orderListId", *id)
@@ -249,9 +357,21 @@ impl BinanceSpot {
         start_time: Option<u64>,
         end_time: Option<u64>,
         limit: Option<u16>,
+    ) -> Result<Response<Vec<OcoOrderRes>>, BinanceError<Error>> {
+        self.get_all_oco_orders_with(from_id, start_time, end_time, limit, &RequestOptions::default()).await
+    }
+
+    /// [`get_all_oco_orders`](Self::get_all_oco_orders) with options for this call.
+    pub async fn get_all_oco_orders_with(
+        &self,
+        from_id: Option<u64>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u16>,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<OcoOrderRes>>, BinanceError<Error>> {
         self.client
-            .get_signed_with_query::<_, _>(endpoints::API_V3_ALL_ORDER_LIST, 256, |url| {
+            .get_signed_with_query::<_, _>(endpoints::API_V3_ALL_ORDER_LIST, 10, opts, 256, |url| {
                 if let Some(id) = from_id {
                     url.add_param_integer("fromId", id);
                 }
@@ -274,9 +394,17 @@ impl BinanceSpot {
     /// Weight(IP): 3
     pub async fn get_all_open_oco_orders(
         &self,
+    ) -> Result<Response<Vec<OcoOrderRes>>, BinanceError<Error>> {
+        self.get_all_open_oco_orders_with(&RequestOptions::default()).await
+    }
+
+    /// [`get_all_open_oco_orders`](Self::get_all_open_oco_orders) with options for this call.
+    pub async fn get_all_open_oco_orders_with(
+        &self,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<OcoOrderRes>>, BinanceError<Error>> {
         self.client
-            .get_signed::<_, _>(endpoints::API_V3_OPEN_ORDER_LIST)
+            .get_signed::<_, _>(endpoints::API_V3_OPEN_ORDER_LIST, 3, opts)
             .await
     }
 
@@ -294,9 +422,22 @@ impl BinanceSpot {
         start_time: Option<u64>,
         end_time: Option<u64>,
         limit: Option<u16>,
+    ) -> Result<Response<Vec<OrderInfo>>, BinanceError<Error>> {
+        self.get_all_account_orders_with(symbol, order_id, start_time, end_time, limit, &RequestOptions::default()).await
+    }
+
+    /// [`get_all_account_orders`](Self::get_all_account_orders) with options for this call.
+    pub async fn get_all_account_orders_with(
+        &self,
+        symbol: &str,
+        order_id: Option<u64>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u16>,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<OrderInfo>>, BinanceError<Error>> {
         self.client
-            .get_signed_with_query::<_, _>(endpoints::API_V3_ALL_ORDERS, 128, |url| {
+            .get_signed_with_query::<_, _>(endpoints::API_V3_ALL_ORDERS, 10, opts, 128, |url| {
                 url.add_param_str("symbol", symbol);
                 if let Some(id) = order_id {
                     url.add_param_integer("orderId", id);
@@ -329,8 +470,16 @@ impl BinanceSpot {
     /// println!("{:?}", reps.payload);
     /// ```
     pub async fn get_account_info(&self) -> Result<Response<AccountInfo>, BinanceError<Error>> {
+        self.get_account_info_with(&RequestOptions::default()).await
+    }
+
+    /// [`get_account_info`](Self::get_account_info) with options for this call.
+    pub async fn get_account_info_with(
+        &self,
+        opts: &RequestOptions,
+    ) -> Result<Response<AccountInfo>, BinanceError<Error>> {
         self.client
-            .get_signed::<_, _>(endpoints::API_V3_ACCOUNT)
+            .get_signed::<_, _>(endpoints::API_V3_ACCOUNT, 10, opts)
             .await
     }
 
@@ -344,9 +493,18 @@ impl BinanceSpot {
     pub async fn get_account_trades(
         &self,
         req: &AccountTradesReq,
+    ) -> Result<Response<Vec<AccountTrade>>, BinanceError<Error>> {
+        self.get_account_trades_with(req, &RequestOptions::default()).await
+    }
+
+    /// [`get_account_trades`](Self::get_account_trades) with options for this call.
+    pub async fn get_account_trades_with(
+        &self,
+        req: &AccountTradesReq,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<AccountTrade>>, BinanceError<Error>> {
         self.client
-            .get_signed_with_query::<_, _>(endpoints::API_V3_MY_TRADES, 128, |url| {
+            .get_signed_with_query::<_, _>(endpoints::API_V3_MY_TRADES, 10, opts, 128, |url| {
                 url.add_params_from_data(&req)?;
                 Ok(())
             })
@@ -359,9 +517,17 @@ impl BinanceSpot {
     ///
     pub async fn get_order_rate_limit(
         &self,
+    ) -> Result<Response<Vec<OrderRateLimit>>, BinanceError<Error>> {
+        self.get_order_rate_limit_with(&RequestOptions::default()).await
+    }
+
+    /// [`get_order_rate_limit`](Self::get_order_rate_limit) with options for this call.
+    pub async fn get_order_rate_limit_with(
+        &self,
+        opts: &RequestOptions,
     ) -> Result<Response<Vec<OrderRateLimit>>, BinanceError<Error>> {
         self.client
-            .get::<_, _>(endpoints::API_V3_RATE_LIMIT_ORDER)
+            .get::<_, _>(endpoints::API_V3_RATE_LIMIT_ORDER, 20, opts)
             .await
     }
 }
@@ -3,14 +3,81 @@ pub mod utils;
 
 pub use binance_schemes as schemes;
 pub use binance_types as types;
+pub use erased_serde;
 
 pub use api::{
+    AccountError,
+    AccountProfile,
+    AccountRegistry,
+    AccountRegistryBuilder,
     BinanceBuilder,
     BinanceDeliveryFutures,
     BinancePerpFutures,
     BinanceSpot,
     BinanceError,
+    BinanceHttpHeader,
+    ApiKey,
+    Clock,
+    CredentialsError,
+    Ed25519Signer,
+    FixedClock,
+    HeaderInterval,
+    HmacSigner,
+    HostPool,
+    HostSelection,
+    HostStatus,
+    Interceptor,
+    IntervalCounts,
+    IntervalUnit,
+    DynJsonDecoder,
+    JsonBackend,
+    JsonDecoder,
+    JsonError,
+    LatencyHistogram,
+    LimitKind,
+    ManualClock,
+    MetricsRegistry,
+    MetricsSink,
+    ProxyConfig,
+    RateLimitMode,
+    RateLimiter,
+    RetryEvent,
+    RetryPolicy,
+    ReqwestTransport,
+    RequestMetrics,
+    RequestOptions,
+    RequestParts,
+    Response,
+    ResponseMeta,
+    RetryReason,
+    RsaSigner,
+    SecretBytes,
+    SecretKey,
+    SecretString,
+    Signer,
+    SignerError,
+    SigningKey,
+    SourceIpPool,
+    SourceIpStatus,
+    SystemClock,
+    TimeOffset,
+    Transport,
+    TransportError,
+    TransportErrorKind,
+    TransportFuture,
+    TransportRequest,
+    TransportResponse,
+    WeightLimit,
     delivery_futures,
     perpetual_futures,
     spot,
 };
+
+#[cfg(feature = "blocking")]
+pub use api::blocking;
+#[cfg(feature = "serde_json")]
+pub use api::SerdeJsonDecoder;
+#[cfg(feature = "simd_json")]
+pub use api::SimdJsonDecoder;
+#[cfg(feature = "sonic_rs")]
+pub use api::SonicRsDecoder;
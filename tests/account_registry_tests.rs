@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    use binancex::spot::trade::endpoints::*;
+    use binancex::spot::trade::prelude::*;
+    use binancex::{
+        AccountError, AccountProfile, AccountRegistry, ApiKey, BinanceError, BinanceSpot, RateLimitMode, SigningKey,
+    };
+    use httpmock::prelude::*;
+
+    const MAIN_API_KEY: &str = "mainxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx";
+    const SUB_API_KEY: &str = "subxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx";
+    const SECRET_KEY: &str = "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx";
+
+    fn profile(name: &str, api_key: &str) -> AccountProfile {
+        AccountProfile::new(name, ApiKey::new(api_key).unwrap(), SigningKey::Hmac(SECRET_KEY.into()))
+    }
+
+    fn market_buy_req() -> NewOrderReq {
+        NewOrderReq {
+            symbol: "BTCUSDT".to_owned(),
+            side: OrderSide::BUY,
+            order_type: OrderTypeReq::Market {
+                time_in_force: None,
+                quantity: Some("0.001".parse().unwrap()),
+                quote_order_qty: None,
+                price: None,
+            },
+            new_client_order_id: None,
+            strategy_id: None,
+            strategy_type: None,
+            new_order_resp_type: None,
+        }
+    }
+
+    #[test]
+    fn test_registry_shares_weight_limiter_and_order_limiter_per_uid() {
+        let registry = AccountRegistry::builder()
+            .profile(profile("main", MAIN_API_KEY).with_uid(1))
+            .profile(profile("main-readonly", SUB_API_KEY).with_uid(1))
+            .profile(profile("sub", SUB_API_KEY))
+            .spot(
+                BinanceSpot::builder()
+                    .rate_limiter(RateLimitMode::FailFast)
+                    .order_rate_limiter(RateLimitMode::FailFast),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(registry.names().collect::<Vec<_>>(), ["main", "main-readonly", "sub"]);
+        assert_eq!(registry.profile("main").unwrap().uid(), Some(1));
+        assert!(registry.spot("missing").is_none());
+
+        let main = registry.spot("main").unwrap();
+        let readonly = registry.spot("main-readonly").unwrap();
+        let sub = registry.spot("sub").unwrap();
+
+        assert!(std::ptr::eq(main.rate_limiter().unwrap(), sub.rate_limiter().unwrap()));
+        assert!(std::ptr::eq(
+            main.order_rate_limiter().unwrap(),
+            readonly.order_rate_limiter().unwrap(),
+        ));
+        assert!(!std::ptr::eq(
+            main.order_rate_limiter().unwrap(),
+            sub.order_rate_limiter().unwrap(),
+        ));
+
+        // the futures clients were built with the defaults
+        assert!(registry.perp_futures("main").unwrap().order_rate_limiter().is_none());
+    }
+
+    #[test]
+    fn test_registry_rejects_duplicate_profile_names() {
+        let registry = AccountRegistry::builder()
+            .profile(profile("main", MAIN_API_KEY))
+            .profile(profile("main", SUB_API_KEY))
+            .build();
+
+        match registry {
+            Err(BinanceError::Account(AccountError::Profile(name, reason))) => {
+                assert_eq!(name, "main");
+                assert_eq!(reason, "defined more than once");
+            }
+            Err(err) => panic!("unexpected error: {:?}", err),
+            Ok(_) => panic!("expected an error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registry_signs_with_account_keys_and_limits_orders_per_account() {
+        let server = MockServer::start_async().await;
+
+        let main_mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path(API_V3_ORDER).header("x-mbx-apikey", MAIN_API_KEY);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight-1m", "1")
+                    .header("x-mbx-order-count-10s", "50")
+                    .body(r#"{"symbol":"BTCUSDT","orderId":28,"orderListId":-1,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","transactTime":1507725176595}"#);
+            })
+            .await;
+
+        let sub_mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path(API_V3_ORDER).header("x-mbx-apikey", SUB_API_KEY);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight-1m", "2")
+                    .header("x-mbx-order-count-10s", "1")
+                    .body(r#"{"symbol":"BTCUSDT","orderId":29,"orderListId":-1,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IQ","transactTime":1507725176595}"#);
+            })
+            .await;
+
+        let registry = AccountRegistry::builder()
+            .profiles([profile("main", MAIN_API_KEY), profile("sub", SUB_API_KEY)])
+            .spot(
+                BinanceSpot::builder()
+                    .host(server.url(""))
+                    .order_rate_limiter(RateLimitMode::FailFast),
+            )
+            .build()
+            .unwrap();
+
+        let main = registry.spot("main").unwrap();
+        let sub = registry.spot("sub").unwrap();
+
+        main.new_order(&market_buy_req()).await.unwrap();
+        // the 10s order limit of the main account is used up
+        match main.new_order(&market_buy_req()).await {
+            Err(BinanceError::RateLimited(_)) => {}
+            other => panic!("expected a rate limit error, got {:?}", other),
+        }
+        sub.new_order(&market_buy_req()).await.unwrap();
+
+        main_mock.assert_hits_async(1).await;
+        sub_mock.assert_hits_async(1).await;
+    }
+}
@@ -0,0 +1,181 @@
+#![cfg(feature = "blocking")]
+
+mod tests {
+    use binancex::blocking::{BinanceDeliveryFutures, BinancePerpFutures, BinanceSpot};
+    use binancex::{RateLimitMode, WeightLimit};
+    use httpmock::prelude::*;
+
+    const API_KEY: &str = "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A";
+    const SECRET_KEY: &str = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j";
+
+    #[test]
+    fn test_blocking_spot_market_get_depth() {
+        use binancex::spot::market::endpoints::*;
+        use binancex::spot::market::prelude::DepthLimit;
+
+        let server = MockServer::start();
+
+        let binance_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path(API_V3_DEPTH)
+                .query_param("symbol", "BTCUSDT")
+                .query_param("limit", "5");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .header("x-mbx-used-weight-1m", "1")
+                .body_from_file("tests/api_dataset/spot/market/depth_2022-03-25.json");
+        });
+
+        let client = BinanceSpot::with_host(server.url("")).unwrap();
+        let response = client.get_depth("BTCUSDT", DepthLimit::Limit(5)).unwrap();
+
+        binance_mock.assert();
+        assert_eq!(response.headers.x_mbx_used_weight_1m, Some(1));
+        assert_eq!(response.payload.asks.len(), 5);
+    }
+
+    #[test]
+    fn test_blocking_spot_signed_request_with_time_sync() {
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        use binancex::spot::market::endpoints::API_V3_TIME;
+        use binancex::spot::trade::endpoints::*;
+
+        let server = MockServer::start();
+
+        let server_time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as i64;
+        let time_mock = server.mock(|when, then| {
+            when.method(GET).path(API_V3_TIME);
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .header("x-mbx-used-weight-1m", "1")
+                .json_body(serde_json::json!({ "serverTime": server_time }));
+        });
+
+        let open_orders_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path(API_V3_OPEN_ORDERS)
+                .header("x-mbx-apikey", API_KEY)
+                .query_param_exists("timestamp")
+                .query_param_exists("signature");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .header("x-mbx-used-weight-1m", "4")
+                .body("[]");
+        });
+
+        // the time sync runs in the background, on the runtime of the blocking clients
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .signed(API_KEY, SECRET_KEY)
+            .server_time_sync_interval(Duration::from_secs(60))
+            .build()
+            .unwrap();
+
+        let response = client.get_all_open_orders().unwrap();
+        assert!(response.payload.is_empty());
+        open_orders_mock.assert();
+
+        let started = std::time::Instant::now();
+        while time_mock.hits() == 0 && started.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        time_mock.assert();
+    }
+
+    #[test]
+    fn test_blocking_perp_futures_sync_rate_limits() {
+        use binancex::perpetual_futures::market::endpoints::*;
+
+        let server = MockServer::start();
+
+        let binance_mock = server.mock(|when, then| {
+            when.method(GET).path(FAPI_V1_EXCHANGE_INFO);
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .header("x-mbx-used-weight-1m", "1")
+                .json_body(serde_json::json!({
+                    "timezone": "UTC",
+                    "serverTime": 1664366400000i64,
+                    "futuresType": "U_MARGINED",
+                    "rateLimits": [
+                        { "rateLimitType": "REQUEST_WEIGHT", "interval": "MINUTE", "intervalNum": 1, "limit": 2400 },
+                        { "rateLimitType": "ORDERS", "interval": "MINUTE", "intervalNum": 1, "limit": 1200 },
+                        { "rateLimitType": "ORDERS", "interval": "SECOND", "intervalNum": 10, "limit": 300 }
+                    ],
+                    "exchangeFilters": [],
+                    "assets": [],
+                    "symbols": []
+                }));
+        });
+
+        let client = BinancePerpFutures::builder()
+            .host(server.url(""))
+            .rate_limiter(RateLimitMode::FailFast)
+            .order_rate_limiter(RateLimitMode::FailFast)
+            .build()
+            .unwrap();
+        client.sync_rate_limits().unwrap();
+
+        binance_mock.assert();
+        assert_eq!(client.rate_limiter().unwrap().limits(), [WeightLimit::per_minute(2400)]);
+        assert_eq!(
+            client.order_rate_limiter().unwrap().limits(),
+            [WeightLimit::per_minute(1200), WeightLimit { interval_ms: 10_000, limit: 300 }],
+        );
+    }
+
+    #[test]
+    fn test_blocking_perp_futures_market_get_depth() {
+        use binancex::perpetual_futures::market::endpoints::*;
+        use binancex::perpetual_futures::market::prelude::DepthLimit;
+
+        let server = MockServer::start();
+
+        let binance_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path(FAPI_V1_DEPTH)
+                .query_param("symbol", "BTCUSDT")
+                .query_param("limit", "5");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .header("x-mbx-used-weight-1m", "2")
+                .body_from_file("tests/api_dataset/perpetual_futures/market/depth_2021-08-15.json");
+        });
+
+        let client = BinancePerpFutures::with_host(server.url("")).unwrap();
+        client.get_depth("BTCUSDT", DepthLimit::Limit5).unwrap();
+
+        binance_mock.assert();
+    }
+
+    #[test]
+    fn test_blocking_delivery_futures_market_get_depth() {
+        use binancex::delivery_futures::market::endpoints::*;
+        use binancex::delivery_futures::market::prelude::DepthLimit;
+
+        let server = MockServer::start();
+
+        let binance_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path(DAPI_V1_DEPTH)
+                .query_param("symbol", "BTCUSDT_PERP")
+                .query_param("limit", "5");
+
+            then.status(200)
+                .header("content-type", "application/json; charset=UTF-8")
+                .header("x-mbx-used-weight-1m", "2")
+                .body_from_file("tests/api_dataset/delivery_futures/market/depth_2021-08-15.json");
+        });
+
+        let client = BinanceDeliveryFutures::with_host(server.url("")).unwrap();
+        client.get_depth("BTCUSDT_PERP", DepthLimit::Limit5).unwrap();
+
+        binance_mock.assert();
+    }
+}
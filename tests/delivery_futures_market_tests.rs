@@ -109,4 +109,87 @@ mod tests {
 
         binance_mock.assert_async().await;
     }
+
+    #[tokio::test]
+    async fn test_futures_dapi_metrics_registry() {
+        use std::sync::Arc;
+
+        use binancex::delivery_futures::market::prelude::DepthLimit;
+        use binancex::{BinanceSpot, MetricsRegistry};
+        use http::{Method, StatusCode};
+
+        let server = MockServer::start_async().await;
+
+        let ping_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(DAPI_V1_PING);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight", "1")
+                    .header("x-mbx-used-weight-1m", "11")
+                    .body("{}");
+            })
+            .await;
+
+        let depth_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(DAPI_V1_DEPTH);
+
+                then.status(400)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight", "2")
+                    .header("x-mbx-used-weight-1m", "13")
+                    .body(r#"{"code":-1121,"msg":"Invalid symbol."}"#);
+            })
+            .await;
+
+        let spot_server = MockServer::start_async().await;
+        let spot_ping_mock = spot_server
+            .mock_async(|when, then| {
+                when.method(GET).path("/api/v3/ping");
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight", "1")
+                    .header("x-mbx-used-weight-1m", "5")
+                    .body("{}");
+            })
+            .await;
+
+        let registry = Arc::new(MetricsRegistry::new());
+        let client = BinanceDeliveryFutures::builder()
+            .host(server.url(""))
+            .metrics(registry.clone())
+            .build()
+            .unwrap();
+        let spot = BinanceSpot::builder()
+            .host(spot_server.url(""))
+            .metrics(registry.clone())
+            .build()
+            .unwrap();
+
+        client.ping().await.unwrap();
+        client.ping().await.unwrap();
+        assert!(client.get_depth("BTCUSD_XXX", DepthLimit::Limit5).await.is_err());
+        spot.ping().await.unwrap();
+
+        ping_mock.assert_hits_async(2).await;
+        depth_mock.assert_async().await;
+        spot_ping_mock.assert_async().await;
+
+        assert_eq!(registry.request_count(&Method::GET, DAPI_V1_PING, Some(StatusCode::OK)), 2);
+        assert_eq!(
+            registry.request_count(&Method::GET, DAPI_V1_DEPTH, Some(StatusCode::BAD_REQUEST)),
+            1,
+        );
+        assert_eq!(registry.latency(&Method::GET, DAPI_V1_PING).unwrap().count, 2);
+        assert_eq!(registry.used_weight_1m(&server.url("")), Some(13));
+        assert_eq!(registry.used_weight_1m(&spot_server.url("")), Some(5));
+        assert_eq!(registry.error_code_count(-1121), 1);
+
+        let text = registry.render_prometheus();
+        assert!(text.contains("# TYPE binance_request_duration_seconds histogram\n"));
+        assert!(text.contains("binance_errors_total{code=\"-1121\"} 1\n"));
+    }
 }
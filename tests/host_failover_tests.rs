@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests {
+    use binancex::spot::market::endpoints::*;
+    use binancex::spot::trade::endpoints::*;
+    use binancex::spot::trade::prelude::*;
+    use binancex::{BinanceError, BinanceSpot, HostSelection};
+    use httpmock::prelude::*;
+
+    const API_KEY: &str = "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx";
+    const SECRET_KEY: &str = "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx";
+
+    const NEW_ORDER_RES: &str = r#"{"symbol":"BTCUSDT","orderId":28,"orderListId":-1,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","transactTime":1507725176595}"#;
+
+    fn market_buy_req() -> NewOrderReq {
+        NewOrderReq {
+            symbol: "BTCUSDT".to_owned(),
+            side: OrderSide::BUY,
+            order_type: OrderTypeReq::Market {
+                time_in_force: None,
+                quantity: Some("0.001".parse().unwrap()),
+                quote_order_qty: None,
+                price: None,
+            },
+            new_client_order_id: None,
+            strategy_id: None,
+            strategy_type: None,
+            new_order_resp_type: None,
+        }
+    }
+
+    /// Address nothing listens on, connecting to it fails right away.
+    fn refused_host() -> String {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}", listener.local_addr().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_get_fails_over_on_server_error() {
+        let primary = MockServer::start_async().await;
+        let fallback = MockServer::start_async().await;
+
+        let primary_mock = primary
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_PING);
+                then.status(503).body("");
+            })
+            .await;
+        let fallback_mock = fallback
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_PING);
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body("{}");
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .hosts([primary.url(""), fallback.url("")])
+            .build()
+            .unwrap();
+
+        let response = client.ping().await.unwrap();
+        assert_eq!(&*response.host, fallback.url("").as_str());
+
+        // the primary stays unhealthy until it answers again
+        let response = client.ping().await.unwrap();
+        assert_eq!(&*response.host, fallback.url("").as_str());
+
+        primary_mock.assert_hits_async(1).await;
+        fallback_mock.assert_hits_async(2).await;
+
+        let status = client.host_pool().unwrap().status();
+        assert!(!status[0].healthy);
+        assert!(status[1].healthy);
+    }
+
+    #[tokio::test]
+    async fn test_new_order_fails_over_on_connect_error() {
+        let fallback = MockServer::start_async().await;
+
+        let fallback_mock = fallback
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path(API_V3_ORDER)
+                    .header("x-mbx-apikey", API_KEY)
+                    .x_www_form_urlencoded_key_exists("signature");
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body(NEW_ORDER_RES);
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .hosts([refused_host(), fallback.url("")])
+            .signed(API_KEY, SECRET_KEY)
+            .build()
+            .unwrap();
+
+        let response = client.new_order(&market_buy_req()).await.unwrap();
+        assert_eq!(&*response.host, fallback.url("").as_str());
+
+        fallback_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_new_order_is_not_failed_over_on_server_error() {
+        let primary = MockServer::start_async().await;
+        let fallback = MockServer::start_async().await;
+
+        let primary_mock = primary
+            .mock_async(|when, then| {
+                when.method(POST).path(API_V3_ORDER);
+                then.status(503)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body(r#"{"code":-1007,"msg":"Timeout waiting for response from backend server. Send status unknown; execution status unknown."}"#);
+            })
+            .await;
+        let fallback_mock = fallback
+            .mock_async(|when, then| {
+                when.method(POST).path(API_V3_ORDER);
+                then.status(200).body(NEW_ORDER_RES);
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .hosts([primary.url(""), fallback.url("")])
+            .signed(API_KEY, SECRET_KEY)
+            .build()
+            .unwrap();
+
+        match client.new_order(&market_buy_req()).await {
+            Err(BinanceError::Binance(resp)) => {
+                assert_eq!(&*resp.host, primary.url("").as_str());
+                assert_eq!(resp.payload.code, -1007);
+            }
+            other => panic!("unexpected result: {:?}", other.map(|resp| resp.status)),
+        }
+
+        primary_mock.assert_async().await;
+        fallback_mock.assert_hits_async(0).await;
+    }
+
+    #[tokio::test]
+    async fn test_check_hosts_routes_to_healthy_host() {
+        let primary = MockServer::start_async().await;
+        let fallback = MockServer::start_async().await;
+
+        primary
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_PING);
+                then.status(500).body("");
+            })
+            .await;
+        fallback
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_PING);
+                then.status(200).body("{}");
+            })
+            .await;
+        let fallback_time_mock = fallback
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_TIME);
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body(r#"{"serverTime":1499827319559}"#);
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .hosts([primary.url(""), fallback.url(""), refused_host()])
+            .host_selection(HostSelection::Fastest)
+            .build()
+            .unwrap();
+
+        client.check_hosts().await;
+
+        let status = client.host_pool().unwrap().status();
+        assert!(!status[0].healthy);
+        assert!(status[1].healthy && status[1].latency.is_some());
+        assert!(!status[2].healthy && status[2].latency.is_none());
+
+        let response = client.get_server_time().await.unwrap();
+        assert_eq!(&*response.host, fallback.url("").as_str());
+        fallback_time_mock.assert_async().await;
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use binancex::spot::market::endpoints::*;
+    use binancex::{BinancePerpFutures, BinanceSpot};
+    use httpmock::prelude::*;
+
+    async fn wait_for_hits(mock: &httpmock::Mock<'_>, hits: usize) {
+        let started = Instant::now();
+        while mock.hits_async().await < hits && started.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spot_warm_up_connections_on_build() {
+        let server = MockServer::start_async().await;
+
+        let ping_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_PING);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight-1m", "1")
+                    .body("{}");
+            })
+            .await;
+
+        let _client = BinanceSpot::builder()
+            .host(server.url(""))
+            .warm_up_connections(3)
+            .pool_max_idle_per_host(3)
+            .build()
+            .unwrap();
+
+        wait_for_hits(&ping_mock, 3).await;
+        ping_mock.assert_hits_async(3).await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_keep_alive_pings_until_client_is_dropped() {
+        let server = MockServer::start_async().await;
+
+        let ping_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_PING);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight-1m", "1")
+                    .body("{}");
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .warm_up_connections(2)
+            .keep_alive_interval(Duration::from_millis(20))
+            .pool_idle_timeout(Duration::from_secs(30))
+            .build()
+            .unwrap();
+
+        wait_for_hits(&ping_mock, 6).await;
+        assert!(ping_mock.hits_async().await >= 6);
+
+        drop(client);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let hits = ping_mock.hits_async().await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(ping_mock.hits_async().await, hits);
+    }
+
+    #[tokio::test]
+    async fn test_perp_futures_warm_up_counts_successful_pings() {
+        use binancex::perpetual_futures::market::endpoints::*;
+
+        let server = MockServer::start_async().await;
+
+        let ping_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(FAPI_V1_PING);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body("{}");
+            })
+            .await;
+
+        let client = BinancePerpFutures::with_host(server.url("")).unwrap();
+        assert_eq!(client.warm_up(4).await, 4);
+        ping_mock.assert_hits_async(4).await;
+
+        let unavailable = BinancePerpFutures::with_host(server.url("/unavailable")).unwrap();
+        assert_eq!(unavailable.warm_up(2).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_spot_http2_prior_knowledge() {
+        let server = MockServer::start_async().await;
+
+        let ping_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_PING);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight-1m", "1")
+                    .body("{}");
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .http2_prior_knowledge(true)
+            .build()
+            .unwrap();
+        client.ping().await.unwrap();
+
+        ping_mock.assert_async().await;
+    }
+}
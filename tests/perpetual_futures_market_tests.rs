@@ -1,9 +1,28 @@
 #[cfg(test)]
 mod tests {
     use binancex::perpetual_futures::market::endpoints::*;
-    use binancex::BinancePerpFutures;
+    use binancex::{BinancePerpFutures, Interceptor, RequestParts, ResponseMeta, TransportError};
     use httpmock::prelude::*;
 
+    /// Tags every request and records what came back.
+    #[derive(Default)]
+    struct TaggingInterceptor {
+        seen: std::sync::Mutex<Vec<(String, Option<u16>)>>,
+    }
+
+    impl Interceptor for TaggingInterceptor {
+        fn before_request(&self, request: &mut RequestParts) {
+            request
+                .headers
+                .insert("x-request-tag", http::HeaderValue::from_static("strategy-1"));
+        }
+
+        fn after_response(&self, request: &RequestParts, outcome: Result<&ResponseMeta, &TransportError>) {
+            let status = outcome.ok().map(|meta| meta.status.as_u16());
+            self.seen.lock().unwrap().push((request.path.to_owned(), status));
+        }
+    }
+
     #[tokio::test]
     async fn test_futures_fapi_general_ping() {
         let server = MockServer::start_async().await;
@@ -26,6 +45,41 @@ mod tests {
         binance_mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_futures_fapi_interceptor_chain() {
+        use std::sync::Arc;
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path(FAPI_V1_PING)
+                    .header("x-request-tag", "strategy-1");
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight", "1")
+                    .header("x-mbx-used-weight-1m", "1")
+                    .body("{}");
+            })
+            .await;
+
+        let interceptor = Arc::new(TaggingInterceptor::default());
+        let client = BinancePerpFutures::builder()
+            .host(server.url(""))
+            .interceptor(interceptor.clone())
+            .build()
+            .unwrap();
+        let _response = client.ping().await.unwrap();
+
+        binance_mock.assert_async().await;
+        assert_eq!(
+            *interceptor.seen.lock().unwrap(),
+            vec![(FAPI_V1_PING.to_owned(), Some(200))],
+        );
+    }
+
     #[tokio::test]
     async fn test_futures_fapi_general_get_server_time() {
         use std::time::{SystemTime, UNIX_EPOCH};
@@ -109,4 +163,36 @@ mod tests {
 
         binance_mock.assert_async().await;
     }
+
+    #[tokio::test]
+    async fn test_futures_fapi_market_get_depth_with_request_options() {
+        use std::time::Duration;
+
+        use binancex::perpetual_futures::market::prelude::DepthLimit;
+        use binancex::{BinanceError, RequestOptions};
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(FAPI_V1_DEPTH).query_param("symbol", "BTCUSDT");
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .delay(Duration::from_millis(500))
+                    .body_from_file(
+                        "tests/api_dataset/perpetual_futures/market/depth_2021-08-15.json",
+                    );
+            })
+            .await;
+
+        let client = BinancePerpFutures::with_host(server.url("")).unwrap();
+        let opts = RequestOptions::new().timeout(Duration::from_millis(50));
+        match client.get_depth_with("BTCUSDT", DepthLimit::Limit5, &opts).await {
+            Err(BinanceError::Transport(err)) => assert!(err.is_timeout()),
+            other => panic!("unexpected result: {:?}", other.map(|resp| resp.status)),
+        }
+
+        binance_mock.assert_async().await;
+    }
 }
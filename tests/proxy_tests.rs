@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use binancex::spot::market::endpoints::*;
+    use binancex::{BinanceError, BinanceSpot, ProxyConfig};
+    use httpmock::prelude::*;
+
+    /// Host that never resolves, requests only get through the proxy.
+    const UNREACHABLE_HOST: &str = "http://binance.invalid";
+
+    #[tokio::test]
+    async fn test_spot_ping_through_http_proxy() {
+        // httpmock answers absolute-form requests, so it stands in for a forward proxy
+        let proxy = MockServer::start_async().await;
+
+        let proxy_mock = proxy
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path(API_V3_PING)
+                    .header("host", "binance.invalid")
+                    // base64 of `user:pass`
+                    .header("proxy-authorization", "Basic dXNlcjpwYXNz");
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body("{}");
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .host(UNREACHABLE_HOST)
+            .proxy(ProxyConfig::new(proxy.url("")).basic_auth("user", "pass"))
+            .build()
+            .unwrap();
+
+        client.ping().await.unwrap();
+        assert_eq!(client.proxy().unwrap().credentials(), Some(("user", "pass")));
+
+        proxy_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_ping_without_proxy_fails() {
+        let client = BinanceSpot::builder().host(UNREACHABLE_HOST).build().unwrap();
+
+        assert!(client.proxy().is_none());
+        assert!(matches!(client.ping().await, Err(BinanceError::Transport(_))));
+    }
+
+    #[test]
+    fn test_proxy_config_debug_redacts_password() {
+        let proxy = ProxyConfig::new("http://proxy.corp:3128").basic_auth("user", "secret");
+        let debug = format!("{:?}", proxy);
+
+        assert!(debug.contains("user"));
+        assert!(!debug.contains("secret"));
+    }
+
+    #[cfg(feature = "socks")]
+    #[tokio::test]
+    async fn test_spot_ping_through_socks5_proxy() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::{TcpListener, TcpStream};
+
+        let server = MockServer::start_async().await;
+        let server_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_PING);
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body("{}");
+            })
+            .await;
+
+        // Minimal SOCKS5 server with username/password auth, every domain is routed to the mock.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy_addr = listener.local_addr().unwrap();
+        let target_addr = *server.address();
+        let proxy = tokio::spawn(async move {
+            let (mut client, _) = listener.accept().await.unwrap();
+
+            let mut greeting = [0u8; 2];
+            client.read_exact(&mut greeting).await.unwrap();
+            let mut methods = vec![0u8; greeting[1] as usize];
+            client.read_exact(&mut methods).await.unwrap();
+            assert!(methods.contains(&0x02));
+            client.write_all(&[0x05, 0x02]).await.unwrap();
+
+            let mut auth = [0u8; 2];
+            client.read_exact(&mut auth).await.unwrap();
+            let mut username = vec![0u8; auth[1] as usize];
+            client.read_exact(&mut username).await.unwrap();
+            let mut password_len = [0u8; 1];
+            client.read_exact(&mut password_len).await.unwrap();
+            let mut password = vec![0u8; password_len[0] as usize];
+            client.read_exact(&mut password).await.unwrap();
+            client.write_all(&[0x01, 0x00]).await.unwrap();
+
+            let mut connect = [0u8; 5];
+            client.read_exact(&mut connect).await.unwrap();
+            assert_eq!(connect[..4], [0x05, 0x01, 0x00, 0x03]);
+            let mut domain = vec![0u8; connect[4] as usize + 2];
+            client.read_exact(&mut domain).await.unwrap();
+            client
+                .write_all(&[0x05, 0x00, 0x00, 0x01, 0, 0, 0, 0, 0, 0])
+                .await
+                .unwrap();
+
+            let mut upstream = TcpStream::connect(target_addr).await.unwrap();
+            let _ = tokio::io::copy_bidirectional(&mut client, &mut upstream).await;
+
+            let domain_len = domain.len() - 2;
+            (username, password, String::from_utf8(domain[..domain_len].to_vec()).unwrap())
+        });
+
+        let client = BinanceSpot::builder()
+            .host(UNREACHABLE_HOST)
+            .proxy(ProxyConfig::new(format!("socks5h://{}", proxy_addr)).basic_auth("user", "pass"))
+            .build()
+            .unwrap();
+
+        client.ping().await.unwrap();
+        drop(client);
+
+        let (username, password, domain) = proxy.await.unwrap();
+        assert_eq!(username, b"user");
+        assert_eq!(password, b"pass");
+        assert_eq!(domain, "binance.invalid");
+
+        server_mock.assert_async().await;
+    }
+}
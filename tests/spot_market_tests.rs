@@ -1,8 +1,15 @@
 #[cfg(test)]
 mod tests {
-    use binancex::{spot::market::endpoints::*, utils::vec_strings_to_string, BinanceSpot};
+    use binancex::{
+        spot::market::endpoints::*, utils::vec_strings_to_string, BinanceError, BinanceSpot,
+        RateLimitMode, RetryPolicy, WeightLimit,
+    };
     use httpmock::prelude::*;
 
+    // a window that does not roll over while the tests run, unlike the minute ones
+    const LONG_WINDOW_HEADER: &str = "x-mbx-used-weight-36500d";
+    const LONG_WINDOW: WeightLimit = WeightLimit { interval_ms: 36500 * 86_400_000, limit: 6000 };
+
     #[tokio::test]
     async fn test_spot_general_ping() {
         let server = MockServer::start_async().await;
@@ -25,6 +32,152 @@ mod tests {
         binance_mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_spot_general_rate_limiter_fail_fast() {
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_PING);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight", "6000")
+                    .header(LONG_WINDOW_HEADER, "6000")
+                    .body("{}");
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .rate_limiter(RateLimitMode::FailFast)
+            .build()
+            .unwrap();
+        client.rate_limiter().unwrap().set_limits(&[LONG_WINDOW]);
+        let _response = client.ping().await.unwrap();
+
+        match client.ping().await {
+            Err(BinanceError::RateLimited(wait)) => assert!(!wait.is_zero()),
+            other => panic!("expected RateLimited, got {:?}", other),
+        }
+        assert_eq!(client.rate_limiter().unwrap().used_weight(LONG_WINDOW.interval_ms), Some(6000));
+
+        binance_mock.assert_hits_async(1).await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_general_rate_limiter_per_source_ip() {
+        use std::net::IpAddr;
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_PING);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight", "6000")
+                    .header(LONG_WINDOW_HEADER, "6000")
+                    .body("{}");
+            })
+            .await;
+
+        let addrs: [IpAddr; 2] = ["127.0.0.1".parse().unwrap(), "127.0.0.2".parse().unwrap()];
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .bind_local_addresses(addrs)
+            .rate_limiter(RateLimitMode::FailFast)
+            .build()
+            .unwrap();
+        assert!(client.rate_limiter().is_none());
+        for addr in addrs {
+            client.source_ip_pool().unwrap().limiter(addr).unwrap().set_limits(&[LONG_WINDOW]);
+        }
+
+        // every address has its own budget, the second request goes out from the fresh one
+        let _response = client.ping().await.unwrap();
+        let _response = client.ping().await.unwrap();
+
+        match client.ping().await {
+            Err(BinanceError::RateLimited(wait)) => assert!(!wait.is_zero()),
+            other => panic!("expected RateLimited, got {:?}", other),
+        }
+        let status = client.source_ip_pool().unwrap().status();
+        assert_eq!(status.iter().map(|s| s.addr).collect::<Vec<_>>(), addrs);
+        assert!(status.iter().all(|s| s.headroom == 0));
+
+        binance_mock.assert_hits_async(2).await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_general_single_local_address_joins_the_pool() {
+        use std::net::IpAddr;
+
+        let addrs: [IpAddr; 3] = [
+            "127.0.0.1".parse().unwrap(),
+            "127.0.0.2".parse().unwrap(),
+            "127.0.0.3".parse().unwrap(),
+        ];
+        let client = BinanceSpot::builder()
+            .bind_local_address(addrs[0])
+            .bind_local_addresses(addrs[1..].iter().copied())
+            .build()
+            .unwrap();
+        let status = client.source_ip_pool().unwrap().status();
+        assert_eq!(status.iter().map(|s| s.addr).collect::<Vec<_>>(), addrs);
+
+        // an address given twice is bound once
+        let client = BinanceSpot::builder()
+            .bind_local_address(addrs[1])
+            .bind_local_addresses(addrs[1..].iter().copied())
+            .build()
+            .unwrap();
+        assert_eq!(client.source_ip_pool().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_spot_general_retry_on_service_unavailable() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_PING);
+
+                then.status(503)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body(r#"{"code":-1001,"msg":"Internal error; unable to process your request. Please try again."}"#);
+            })
+            .await;
+
+        let retries = Arc::new(AtomicU32::new(0));
+        let retries_counter = retries.clone();
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .retry_policy(
+                RetryPolicy::new(3)
+                    .base_delay(Duration::from_millis(1))
+                    .on_retry(move |event| {
+                        assert_eq!(event.path, API_V3_PING);
+                        retries_counter.fetch_add(1, Ordering::SeqCst);
+                    }),
+            )
+            .build()
+            .unwrap();
+
+        match client.ping().await {
+            Err(BinanceError::Binance(resp)) => assert_eq!(resp.status.as_u16(), 503),
+            other => panic!("expected Binance error, got {:?}", other),
+        }
+        assert_eq!(retries.load(Ordering::SeqCst), 2);
+
+        binance_mock.assert_hits_async(3).await;
+    }
+
     #[tokio::test]
     async fn test_spot_general_get_server_time() {
         use std::time::{SystemTime, UNIX_EPOCH};
@@ -105,6 +258,80 @@ mod tests {
         binance_mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_spot_market_get_depth_raw_borrowed() {
+        use binancex::schemes::spot::market::OrderBookRef;
+        use binancex::spot::market::prelude::DepthLimit;
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path(API_V3_DEPTH)
+                    .query_param("symbol", "BTCUSDT")
+                    .query_param("limit", "5");
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight-1m", "1")
+                    .body_from_file("tests/api_dataset/spot/market/depth_2022-03-25.json");
+            })
+            .await;
+
+        let client = BinanceSpot::with_host(server.url("")).unwrap();
+        let response = client
+            .get_depth_raw("BTCUSDT", DepthLimit::Limit(5))
+            .await
+            .unwrap();
+        assert_eq!(response.headers.x_mbx_used_weight_1m, Some(1));
+
+        let book: OrderBookRef = response.parse().unwrap();
+        assert_eq!(book.asks.len(), 5);
+        assert_eq!(book.asks[0].price, "44364.07000000");
+        assert_eq!(book.asks[0].parse().unwrap().qty, "0.37308".parse().unwrap());
+
+        binance_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_market_keep_raw_body() {
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_TICKER_BOOK_TICKER);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body_from_file(
+                        "tests/api_dataset/spot/market/ticker_bookTicker_2022-10-06.json",
+                    );
+            })
+            .await;
+
+        let body = std::fs::read("tests/api_dataset/spot/market/ticker_bookTicker_2022-10-06.json").unwrap();
+
+        let client = BinanceSpot::with_host(server.url("")).unwrap();
+        let response = client.get_book_ticker("BTCUSDT").await.unwrap();
+        assert!(response.raw.is_none());
+
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .keep_raw_body(true)
+            .build()
+            .unwrap();
+        let response = client.get_book_ticker("BTCUSDT").await.unwrap();
+        assert_eq!(response.raw.as_deref(), Some(&body[..]));
+
+        let response = client.get_book_ticker_raw("BTCUSDT").await.unwrap();
+        let ticker: binancex::schemes::spot::market::BookTickerRef = response.parse().unwrap();
+        assert_eq!(ticker.symbol, "BTCUSDT");
+        assert_eq!(response.raw.as_deref(), Some(&body[..]));
+
+        binance_mock.assert_hits_async(3).await;
+    }
+
     #[tokio::test]
     async fn test_spot_market_get_recent_trades() {
         let server = MockServer::start_async().await;
@@ -236,6 +463,50 @@ mod tests {
         binance_mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_spot_market_get_klines_with_request_options() {
+        use std::time::Duration;
+
+        use binancex::spot::market::prelude::KLinesInterval;
+        use binancex::RequestOptions;
+        use reqwest::header::{HeaderName, HeaderValue};
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path(API_V3_KLINES)
+                    .header("x-request-tag", "history")
+                    .query_param("symbol", "BTCUSDT")
+                    .query_param("interval", "1m");
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight-1m", "1")
+                    .delay(Duration::from_millis(200))
+                    .body_from_file("tests/api_dataset/spot/market/klines_2022-10-06.json");
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .http_request_timeout_ms(Duration::from_millis(50))
+            .build()
+            .unwrap();
+        let opts = RequestOptions::new()
+            .timeout(Duration::from_secs(5))
+            .header(HeaderName::from_static("x-request-tag"), HeaderValue::from_static("history"));
+
+        let response = client
+            .get_klines_with("BTCUSDT", KLinesInterval::Minute, None, None, Some(2), &opts)
+            .await
+            .unwrap();
+        assert_eq!(response.headers.x_mbx_used_weight_1m, Some(1));
+
+        binance_mock.assert_async().await;
+    }
+
     #[tokio::test]
     async fn test_spot_market_get_klines_ui() {
         use binancex::types::spot::limits::KLinesInterval;
@@ -444,6 +715,34 @@ mod tests {
         binance_mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_spot_market_get_latest_price_with_request_options() {
+        use binancex::RequestOptions;
+        use reqwest::header::{HeaderName, HeaderValue};
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path(API_V3_TICKER_PRICE)
+                    .header("x-request-tag", "quotes")
+                    .query_param("symbol", "BTCUSDT");
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body_from_file("tests/api_dataset/spot/market/ticker_price_2022-10-06.json");
+            })
+            .await;
+
+        let client = BinanceSpot::with_host(server.url("")).unwrap();
+        let opts = RequestOptions::new()
+            .header(HeaderName::from_static("x-request-tag"), HeaderValue::from_static("quotes"));
+        let _response = client.get_latest_price_with("BTCUSDT", &opts).await.unwrap();
+
+        binance_mock.assert_async().await;
+    }
+
     #[tokio::test]
     async fn test_spot_market_get_latest_prices() {
         let server = MockServer::start_async().await;
@@ -531,4 +830,117 @@ mod tests {
 
         binance_mock.assert_async().await;
     }
+
+    #[tokio::test]
+    async fn test_spot_market_get_depth_parse_error_has_path() {
+        use binancex::spot::market::prelude::DepthLimit;
+        use binancex::JsonBackend;
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_DEPTH);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body(r#"{"lastUpdateId":1027024,"bids":[["4.00000000","431.00000000"]],"asks":[["4.00000200",null]]}"#);
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .json_backend(JsonBackend::default())
+            .build()
+            .unwrap();
+
+        match client.get_depth("BTCUSDT", DepthLimit::Limit(5)).await {
+            Err(BinanceError::ParseJson(err)) => {
+                assert_eq!(err.backend(), Some(JsonBackend::default()));
+                assert_eq!(err.path(), Some("asks[0][1]"));
+                assert!(err.excerpt().contains("4.00000200"), "{}", err);
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+
+        binance_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_market_get_depth_with_custom_json_decoder() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        use binancex::erased_serde;
+        use binancex::spot::market::prelude::DepthLimit;
+        use binancex::DynJsonDecoder;
+
+        #[derive(Default)]
+        struct CountingDecoder {
+            calls: AtomicUsize,
+        }
+
+        impl DynJsonDecoder for CountingDecoder {
+            fn name(&self) -> &'static str {
+                "counting"
+            }
+
+            fn deserialize(
+                &self,
+                body: &[u8],
+                visit: &mut dyn FnMut(&mut dyn erased_serde::Deserializer) -> Result<(), erased_serde::Error>,
+            ) -> Result<(), erased_serde::Error> {
+                self.calls.fetch_add(1, Ordering::Relaxed);
+                let mut deserializer = serde_json::Deserializer::from_slice(body);
+                visit(&mut <dyn erased_serde::Deserializer>::erase(&mut deserializer))?;
+                deserializer.end().map_err(serde::de::Error::custom)
+            }
+        }
+
+        let server = MockServer::start_async().await;
+
+        let depth_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_DEPTH).query_param("symbol", "BTCUSDT");
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body_from_file("tests/api_dataset/spot/market/depth_2022-03-25.json");
+            })
+            .await;
+
+        let bad_depth_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_DEPTH).query_param("symbol", "ETHUSDT");
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body(r#"{"lastUpdateId":1027024,"bids":[],"asks":[["4.00000200",null]]}"#);
+            })
+            .await;
+
+        let decoder = Arc::new(CountingDecoder::default());
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .json_decoder(decoder.clone())
+            .build()
+            .unwrap();
+
+        let response = client.get_depth("BTCUSDT", DepthLimit::Limit(5)).await.unwrap();
+        assert_eq!(response.payload.asks.len(), 5);
+        assert_eq!(decoder.calls.load(Ordering::Relaxed), 1);
+
+        match client.get_depth("ETHUSDT", DepthLimit::Limit(5)).await {
+            Err(BinanceError::ParseJson(err)) => {
+                assert_eq!(err.decoder(), "counting");
+                assert_eq!(err.backend(), None);
+                assert_eq!(err.path(), Some("asks[0][1]"));
+                assert!(err.excerpt().contains("null"), "{}", err);
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+
+        depth_mock.assert_async().await;
+        bad_depth_mock.assert_async().await;
+    }
 }
@@ -0,0 +1,620 @@
+#[cfg(test)]
+mod tests {
+    use binancex::spot::market::endpoints::*;
+    use binancex::spot::trade::endpoints::*;
+    use binancex::spot::trade::prelude::*;
+    use binancex::{BinanceError, BinanceSpot, HeaderInterval, IntervalUnit, RetryPolicy};
+    use httpmock::prelude::*;
+
+    const API_KEY: &str = "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx";
+    const SECRET_KEY: &str = "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx";
+
+    fn market_buy_req() -> NewOrderReq {
+        NewOrderReq {
+            symbol: "BTCUSDT".to_owned(),
+            side: OrderSide::BUY,
+            order_type: OrderTypeReq::Market {
+                time_in_force: None,
+                quantity: Some("0.001".parse().unwrap()),
+                quote_order_qty: None,
+                price: None,
+            },
+            new_client_order_id: None,
+            strategy_id: None,
+            strategy_type: None,
+            new_order_resp_type: None,
+        }
+    }
+
+    fn body_param(req: &HttpMockRequest, key: &str) -> Option<String> {
+        let body = std::str::from_utf8(req.body.as_deref()?).ok()?;
+        let mut url = reqwest::Url::parse("http://localhost").unwrap();
+        url.set_query(Some(body));
+        url.query_pairs().find(|(k, _)| k == key).map(|(_, v)| v.into_owned())
+    }
+
+    fn now_ms() -> i64 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as i64
+    }
+
+    #[tokio::test]
+    async fn test_spot_sync_server_time_offsets_signed_timestamp() {
+        let server = MockServer::start_async().await;
+
+        let time_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_TIME);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight", "1")
+                    .header("x-mbx-used-weight-1m", "1")
+                    .json_body(serde_json::json!({ "serverTime": now_ms() + 60_000 }));
+            })
+            .await;
+
+        let open_orders_mock = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path(API_V3_OPEN_ORDERS)
+                    .header("x-mbx-apikey", API_KEY)
+                    .query_param_exists("signature")
+                    .matches(|req| {
+                        let timestamp: i64 = req
+                            .query_params
+                            .as_ref()
+                            .and_then(|params| params.iter().find(|(k, _)| k == "timestamp"))
+                            .map(|(_, v)| v.parse().unwrap())
+                            .unwrap();
+                        (timestamp - now_ms() - 60_000).abs() < 5_000
+                    });
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight", "40")
+                    .header("x-mbx-used-weight-1m", "41")
+                    .body("[]");
+            })
+            .await;
+
+        let client = BinanceSpot::signed_with_host(server.url(""), API_KEY, SECRET_KEY).unwrap();
+        let offset = client.sync_server_time().await.unwrap();
+        assert!((offset.offset_ms - 60_000).abs() < 5_000);
+        assert_eq!(client.time_offset(), offset);
+
+        let _response = client.get_all_open_orders().await.unwrap();
+
+        time_mock.assert_async().await;
+        open_orders_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_new_order_is_not_retried_on_server_error() {
+        use std::time::Duration;
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path(API_V3_ORDER);
+
+                then.status(503)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body(r#"{"code":-1007,"msg":"Timeout waiting for response from backend server. Send status unknown; execution status unknown."}"#);
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .signed(API_KEY, SECRET_KEY)
+            .retry_policy(RetryPolicy::new(3).base_delay(Duration::from_millis(1)))
+            .build()
+            .unwrap();
+
+        match client.new_order(&market_buy_req()).await {
+            Err(err @ BinanceError::Binance(_)) => {
+                assert_eq!(err.error_code().map(|code| code.code()), Some(-1007));
+                assert!(!err.is_retryable());
+            }
+            other => panic!("expected Binance error, got {:?}", other),
+        }
+
+        binance_mock.assert_hits_async(1).await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_new_order_timeout_is_not_retryable() {
+        use std::time::Duration;
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path(API_V3_ORDER);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .delay(Duration::from_millis(500))
+                    .body("{}");
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .signed(API_KEY, SECRET_KEY)
+            .http_request_timeout_ms(Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        // the order may have reached the matching engine
+        match client.new_order(&market_buy_req()).await {
+            Err(err @ BinanceError::Transport(_)) => assert!(!err.is_retryable()),
+            other => panic!("expected a transport error, got {:?}", other.map(|resp| resp.status)),
+        }
+
+        binance_mock.assert_hits_async(1).await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_new_order_error_classification() {
+        use binancex::schemes::error::ApiError;
+        use binancex::types::error::{ErrorCategory, ErrorCode};
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path(API_V3_ORDER);
+
+                then.status(400)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight-1m", "3")
+                    .header("x-mbx-order-count-10s", "many")
+                    .body(r#"{"code":-2010,"msg":"Account has insufficient balance for requested action."}"#);
+            })
+            .await;
+
+        let client = BinanceSpot::signed_with_host(server.url(""), API_KEY, SECRET_KEY).unwrap();
+        let err = client.new_order(&market_buy_req()).await.unwrap_err();
+
+        binance_mock.assert_async().await;
+        assert_eq!(err.error_code(), Some(ErrorCode::NewOrderRejected));
+        assert_eq!(err.category(), Some(ErrorCategory::InsufficientBalance));
+        assert!(!err.is_retryable());
+        let headers = err.headers().unwrap();
+        assert_eq!(headers.used_weight.get_ms(60_000), Some(3));
+        assert!(headers.order_count.is_empty());
+        assert_eq!(
+            err.to_string(),
+            "binance error (HTTP 400 Bad Request): -2010: Account has insufficient balance for requested action.",
+        );
+
+        if let BinanceError::Binance(ref resp) = err {
+            assert_eq!(resp.payload.category(), ErrorCategory::InsufficientBalance);
+        }
+
+        let err: Box<dyn std::error::Error> = Box::new(err);
+        assert!(err.source().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_spot_rsa_signed_request_sends_url_encoded_signature() {
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path(API_V3_OPEN_ORDERS)
+                    .header("x-mbx-apikey", API_KEY)
+                    .matches(|req| {
+                        // `+`, `/` and `=` must survive the query decoding
+                        req.query_params
+                            .as_ref()
+                            .and_then(|params| params.iter().find(|(k, _)| k == "signature"))
+                            .and_then(|(_, v)| base64::decode(v).ok())
+                            .is_some_and(|signature| signature.len() == 256)
+                    });
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight", "40")
+                    .header("x-mbx-used-weight-1m", "40")
+                    .body("[]");
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .signed_rsa(API_KEY, include_str!("keys/rsa_2048_test.pem"))
+            .build()
+            .unwrap();
+
+        let _response = client.get_all_open_orders().await.unwrap();
+
+        binance_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_ed25519_signed_new_order() {
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path(API_V3_ORDER)
+                    .header("x-mbx-apikey", API_KEY)
+                    .x_www_form_urlencoded_tuple("symbol", "BTCUSDT")
+                    .matches(|req| {
+                        body_param(req, "signature")
+                            .and_then(|v| base64::decode(v).ok())
+                            .is_some_and(|signature| signature.len() == 64)
+                    });
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight", "1")
+                    .header("x-mbx-used-weight-1m", "1")
+                    .header("x-mbx-order-count-10s", "1")
+                    .header("x-mbx-order-count-1d", "1")
+                    .body(r#"{"symbol":"BTCUSDT","orderId":28,"orderListId":-1,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","transactTime":1507725176595}"#);
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .signed_ed25519(API_KEY, include_str!("keys/ed25519_test.pem"))
+            .build()
+            .unwrap();
+
+        let response = client.new_order(&market_buy_req()).await.unwrap();
+
+        binance_mock.assert_async().await;
+        assert_eq!(
+            response.headers.order_count.get(HeaderInterval::new(10, IntervalUnit::Second)),
+            Some(1),
+        );
+        assert_eq!(response.headers.order_count.get(HeaderInterval::new(1, IntervalUnit::Day)), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_spot_new_oco_order_sends_signed_params_in_body() {
+        use binancex::schemes::spot::trade::{NewOcoOrderReq, OrderResponseType, TimeInForce};
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path(API_V3_ORDER_OCO)
+                    .header("x-mbx-apikey", API_KEY)
+                    .header("content-type", "application/x-www-form-urlencoded")
+                    .body_contains("symbol=BTCUSDT&side=SELL&quantity=0.001&price=30000")
+                    .body_contains("&timestamp=")
+                    .matches(|req| {
+                        let body = req.body.as_deref().and_then(|body| std::str::from_utf8(body).ok());
+                        req.query_params.as_ref().is_none_or(|params| params.is_empty())
+                            && body
+                                .and_then(|body| body.split_once("&signature="))
+                                .is_some_and(|(_, signature)| {
+                                    signature.len() == 64 && signature.bytes().all(|b| b.is_ascii_hexdigit())
+                                })
+                    });
+
+                then.status(400)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body(r#"{"code":-1013,"msg":"Filter failure: PRICE_FILTER"}"#);
+            })
+            .await;
+
+        let client = BinanceSpot::signed_with_host(server.url(""), API_KEY, SECRET_KEY).unwrap();
+        let req = NewOcoOrderReq {
+            symbol: "BTCUSDT".to_owned(),
+            list_client_order_id: None,
+            side: OrderSide::SELL,
+            quantity: "0.001".parse().unwrap(),
+            limit_client_order_id: None,
+            limit_strategy_id: None,
+            limit_strategy_type: None,
+            price: "30000".parse().unwrap(),
+            limit_iceberg_qty: None,
+            trailing_delta: None,
+            stop_client_order_id: None,
+            stop_price: "20000".parse().unwrap(),
+            stop_strategy_id: None,
+            stop_strategy_type: None,
+            stop_limit_price: None,
+            stop_iceberg_qty: None,
+            stop_limit_time_in_force: TimeInForce::GTC,
+            new_order_resp_type: OrderResponseType::Ack,
+        };
+
+        match client.new_oco_order(&req).await {
+            Err(BinanceError::Binance(resp)) => assert_eq!(resp.payload.code, -1013),
+            other => panic!("unexpected result: {:?}", other.map(|resp| resp.status)),
+        }
+
+        binance_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_order_requests_send_signed_params_in_body() {
+        fn signed_in_body(req: &HttpMockRequest) -> bool {
+            req.query_params.as_ref().is_none_or(|params| params.is_empty())
+                && body_param(req, "timestamp").is_some()
+                && body_param(req, "signature").is_some_and(|signature| signature.len() == 64)
+        }
+
+        let server = MockServer::start_async().await;
+
+        let mocks = [
+            (POST, API_V3_ORDER_TEST, "symbol=BTCUSDT&side=BUY&type=MARKET&quantity=0.001"),
+            (POST, API_V3_ORDER, "symbol=BTCUSDT&side=BUY&type=MARKET&quantity=0.001"),
+            (DELETE, API_V3_ORDER, "symbol=BTCUSDT&orderId=28"),
+            (DELETE, API_V3_OPEN_ORDERS, "symbol=BTCUSDT"),
+            (DELETE, API_V3_ORDER_LIST, "symbol=BTCUSDT&orderListId=1929"),
+        ];
+        let mut binance_mocks = Vec::new();
+        for (method, path, params) in mocks {
+            let mock = server
+                .mock_async(|when, then| {
+                    when.method(method)
+                        .path(path)
+                        .header("x-mbx-apikey", API_KEY)
+                        .header("content-type", "application/x-www-form-urlencoded")
+                        .body_contains(params)
+                        .matches(signed_in_body);
+
+                    then.status(400)
+                        .header("content-type", "application/json; charset=UTF-8")
+                        .body(r#"{"code":-2011,"msg":"Unknown order sent."}"#);
+                })
+                .await;
+            binance_mocks.push(mock);
+        }
+
+        let client = BinanceSpot::signed_with_host(server.url(""), API_KEY, SECRET_KEY).unwrap();
+        let cancel_req = CancelOrderReq {
+            symbol: "BTCUSDT".to_owned(),
+            order_id: Some(28),
+            orig_client_order_id: None,
+            new_client_order_id: None,
+        };
+
+        assert!(matches!(client.test_new_order(&market_buy_req()).await, Err(BinanceError::Binance(_))));
+        assert!(matches!(client.new_order(&market_buy_req()).await, Err(BinanceError::Binance(_))));
+        assert!(matches!(client.cancel_order(&cancel_req).await, Err(BinanceError::Binance(_))));
+        assert!(matches!(client.cancel_all_orders("BTCUSDT").await, Err(BinanceError::Binance(_))));
+        assert!(matches!(
+            client.cancel_oco_order("BTCUSDT", Some(1929), None, None).await,
+            Err(BinanceError::Binance(_))
+        ));
+
+        for mock in binance_mocks {
+            mock.assert_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spot_new_order_with_request_options() {
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        use binancex::{ApiKey, HmacSigner, RequestOptions};
+        use reqwest::header::{HeaderName, HeaderValue};
+
+        const OTHER_API_KEY: &str = "yyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy";
+
+        let server = MockServer::start_async().await;
+
+        let fast_mock = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path(API_V3_ORDER)
+                    .header("x-mbx-apikey", OTHER_API_KEY)
+                    .header("x-request-tag", "latency-critical")
+                    .x_www_form_urlencoded_tuple("recvWindow", "250")
+                    .x_www_form_urlencoded_key_exists("signature");
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body(r#"{"symbol":"BTCUSDT","orderId":28,"orderListId":-1,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","transactTime":1507725176595}"#);
+            })
+            .await;
+
+        let client = BinanceSpot::signed_with_host(server.url(""), API_KEY, SECRET_KEY).unwrap();
+        let opts = RequestOptions::new()
+            .recv_window(250)
+            .header(HeaderName::from_static("x-request-tag"), HeaderValue::from_static("latency-critical"))
+            .credentials(ApiKey::new(OTHER_API_KEY).unwrap(), Arc::new(HmacSigner::new(b"other secret")));
+
+        assert!(client.new_order_with(&market_buy_req(), &opts).await.is_ok());
+        fast_mock.assert_async().await;
+
+        let slow_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_ACCOUNT).header("x-mbx-apikey", API_KEY);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .delay(Duration::from_millis(500))
+                    .body("{}");
+            })
+            .await;
+
+        let opts = RequestOptions::new().timeout(Duration::from_millis(50));
+        match client.get_account_info_with(&opts).await {
+            Err(BinanceError::Transport(err)) => assert!(err.is_timeout()),
+            other => panic!("unexpected result: {:?}", other.map(|resp| resp.status)),
+        }
+        slow_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_new_order_with_credentials_counts_orders_of_that_account() {
+        use std::sync::Arc;
+
+        use binancex::{ApiKey, HmacSigner, RateLimitMode, RateLimiter, RequestOptions, WeightLimit};
+
+        const OTHER_API_KEY: &str = "yyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyy";
+        // a window that does not roll over while the test runs
+        const ONE_ORDER: WeightLimit = WeightLimit { interval_ms: 100 * 365 * 86_400_000, limit: 1 };
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path(API_V3_ORDER);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body(r#"{"symbol":"BTCUSDT","orderId":28,"orderListId":-1,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","transactTime":1507725176595}"#);
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .signed(API_KEY, SECRET_KEY)
+            .order_rate_limiter(RateLimitMode::FailFast)
+            .build()
+            .unwrap();
+        client.order_rate_limiter().unwrap().set_limits(&[ONE_ORDER]);
+
+        let other_limiter = Arc::new(RateLimiter::orders(RateLimitMode::FailFast, &[ONE_ORDER]));
+        let opts = RequestOptions::new()
+            .credentials(ApiKey::new(OTHER_API_KEY).unwrap(), Arc::new(HmacSigner::new(b"other secret")))
+            .order_limiter(other_limiter);
+
+        assert!(client.new_order_with(&market_buy_req(), &opts).await.is_ok());
+        assert!(matches!(
+            client.new_order_with(&market_buy_req(), &opts).await,
+            Err(BinanceError::RateLimited(_)),
+        ));
+
+        // the order of the other account left the limit of the client's account untouched
+        assert!(client.new_order(&market_buy_req()).await.is_ok());
+        assert!(matches!(client.new_order(&market_buy_req()).await, Err(BinanceError::RateLimited(_))));
+
+        binance_mock.assert_hits_async(2).await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_signed_request_with_fixed_clock() {
+        use std::sync::Arc;
+
+        use binancex::{FixedClock, HmacSigner, RequestOptions, Signer};
+
+        let server = MockServer::start_async().await;
+
+        let query = "recvWindow=5000&timestamp=1499827319559";
+        let mut signature = String::new();
+        HmacSigner::new(SECRET_KEY.as_bytes()).sign(query.as_bytes(), &mut signature).unwrap();
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path(API_V3_OPEN_ORDERS)
+                    .query_param("recvWindow", "5000")
+                    .query_param("timestamp", "1499827319559")
+                    .query_param("signature", &signature);
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body("[]");
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .signed(API_KEY, SECRET_KEY)
+            .clock(Arc::new(FixedClock::from_millis(1499827319559)))
+            .build()
+            .unwrap();
+
+        let opts = RequestOptions::new().recv_window(5000);
+        let _response = client.get_all_open_orders_with(&opts).await.unwrap();
+
+        binance_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_spot_new_order_in_microseconds() {
+        use binancex::schemes::spot::trade::NewOrderRes;
+        use binancex::types::time::{TimeUnit, Timestamp};
+
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path(API_V3_ORDER)
+                    .header("x-mbx-time-unit", "MICROSECOND")
+                    .x_www_form_urlencoded_key_exists("signature")
+                    .matches(|req| {
+                        let timestamp: i64 = body_param(req, "timestamp").unwrap().parse().unwrap();
+                        (timestamp / 1000 - now_ms()).abs() < 5_000
+                    });
+
+                then.status(200)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .body(r#"{"symbol":"BTCUSDT","orderId":28,"orderListId":-1,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","transactTime":1507725176595123}"#);
+            })
+            .await;
+
+        let client = BinanceSpot::builder()
+            .host(server.url(""))
+            .signed(API_KEY, SECRET_KEY)
+            .time_unit(TimeUnit::Microsecond)
+            .build()
+            .unwrap();
+        assert_eq!(client.time_unit(), TimeUnit::Microsecond);
+
+        match client.new_order(&market_buy_req()).await.unwrap().payload {
+            NewOrderRes::Ack(ack) => {
+                assert_eq!(ack.transact_time, Timestamp::from_micros(1507725176595123));
+                assert_eq!(ack.transact_time.unit(), TimeUnit::Microsecond);
+                assert_eq!(ack.transact_time.as_millis(), 1507725176595);
+            }
+            other => panic!("unexpected response: {:?}", other),
+        }
+
+        binance_mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_spot_signed_rsa_rejects_invalid_key() {
+        let result = BinanceSpot::builder()
+            .signed_rsa(API_KEY, "not a pem")
+            .build();
+
+        assert!(matches!(result, Err(BinanceError::Signer(_))));
+    }
+
+    #[test]
+    fn test_spot_signed_rejects_invalid_keys() {
+        use binancex::{CredentialsError, SignerError};
+
+        // a key pasted with its line break used to panic on the first request
+        let result = BinanceSpot::signed(format!("{}\n", API_KEY), SECRET_KEY);
+        assert!(matches!(
+            result,
+            Err(BinanceError::Credentials(CredentialsError::InvalidCharacter("api key"))),
+        ));
+
+        let result = BinanceSpot::builder().signed(API_KEY, "").build();
+        assert!(matches!(
+            result,
+            Err(BinanceError::Signer(SignerError::Credentials(CredentialsError::Empty("secret key")))),
+        ));
+    }
+
+    #[test]
+    fn test_spot_signed_accepts_string_refs() {
+        let api_key = API_KEY.to_owned();
+        let secret_key = SECRET_KEY.to_owned();
+
+        assert!(BinanceSpot::signed(&api_key, &secret_key).is_ok());
+        assert!(BinanceSpot::builder().signed(&api_key, &secret_key).build().is_ok());
+    }
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "tracing")]
+
+mod tests {
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    use binancex::spot::trade::endpoints::*;
+    use binancex::BinanceSpot;
+    use httpmock::prelude::*;
+    use tracing_subscriber::fmt::format::FmtSpan;
+
+    const API_KEY: &str = "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A";
+    const SECRET_KEY: &str = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j";
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_span_records_fields_and_redacts_secrets() {
+        let server = MockServer::start_async().await;
+
+        let binance_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(API_V3_OPEN_ORDERS);
+
+                then.status(400)
+                    .header("content-type", "application/json; charset=UTF-8")
+                    .header("x-mbx-used-weight", "3")
+                    .header("x-mbx-used-weight-1m", "3")
+                    .body(r#"{"code":-1121,"msg":"Invalid symbol."}"#);
+            })
+            .await;
+
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_span_events(FmtSpan::CLOSE)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let client = BinanceSpot::signed_with_host(server.url(""), API_KEY, SECRET_KEY).unwrap();
+        assert!(client.get_all_open_orders().await.is_err());
+
+        binance_mock.assert_async().await;
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let close = output
+            .lines()
+            .find(|line| line.contains("binance_request{") && line.contains("close"))
+            .expect("request span is closed");
+        assert!(close.contains("method=GET"));
+        assert!(close.contains(&format!("path=\"{}\"", API_V3_OPEN_ORDERS)));
+        assert!(close.contains("attempts=1"));
+        assert!(close.contains("status=400"));
+        assert!(close.contains("used_weight=3"));
+        assert!(close.contains("error_code=-1121"));
+        assert!(close.contains("latency_ms="));
+
+        assert!(output.contains("signature=<redacted>"));
+        assert!(!output.contains(API_KEY));
+        assert!(!output.contains(SECRET_KEY));
+    }
+}
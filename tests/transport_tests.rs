@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use binancex::spot::market::endpoints::*;
+    use binancex::types::time::Timestamp;
+    use binancex::{
+        BinanceError, BinanceSpot, Interceptor, RequestParts, ResponseMeta, RetryPolicy, Transport,
+        TransportError, TransportFuture, TransportRequest, TransportResponse,
+    };
+    use http::{header::HeaderMap, Method, StatusCode};
+
+    #[derive(Debug)]
+    struct SentRequest {
+        method: Method,
+        url: String,
+        api_key: Option<String>,
+    }
+
+    /// Answers requests from a queue without touching the network.
+    #[derive(Default)]
+    struct MemoryTransport {
+        responses: Mutex<VecDeque<Result<TransportResponse, TransportError>>>,
+        sent: Mutex<Vec<SentRequest>>,
+    }
+
+    impl MemoryTransport {
+        fn respond(&self, status: u16, used_weight_1m: u16, body: &'static str) {
+            let mut headers = HeaderMap::new();
+            headers.insert("x-mbx-used-weight-1m", used_weight_1m.into());
+            self.responses.lock().unwrap().push_back(Ok(TransportResponse {
+                status: StatusCode::from_u16(status).unwrap(),
+                headers,
+                body: body.into(),
+            }));
+        }
+
+        fn fail(&self, err: TransportError) {
+            self.responses.lock().unwrap().push_back(Err(err));
+        }
+    }
+
+    impl Transport for MemoryTransport {
+        fn send<'a>(&'a self, request: TransportRequest<'a>) -> TransportFuture<'a> {
+            self.sent.lock().unwrap().push(SentRequest {
+                method: request.method.clone(),
+                url: request.url.to_owned(),
+                api_key: request
+                    .headers
+                    .get("x-mbx-apikey")
+                    .map(|v| v.to_str().unwrap().to_owned()),
+            });
+            let response = self
+                .responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("unexpected request");
+
+            Box::pin(async move { response })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_memory_transport_serves_market_request() {
+        let transport = Arc::new(MemoryTransport::default());
+        transport.respond(200, 1, r#"{"serverTime":1499827319559}"#);
+
+        let client = BinanceSpot::builder()
+            .host("https://api.binance.test")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let response = client.get_server_time().await.unwrap();
+        assert_eq!(response.payload.server_time, Timestamp::from_millis(1499827319559));
+        assert_eq!(response.headers.x_mbx_used_weight_1m, Some(1));
+
+        let sent = transport.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].method, Method::GET);
+        assert_eq!(sent[0].url, format!("https://api.binance.test{}", API_V3_TIME));
+        assert_eq!(sent[0].api_key, None);
+    }
+
+    #[tokio::test]
+    async fn test_memory_transport_connect_error_is_retried() {
+        let transport = Arc::new(MemoryTransport::default());
+        transport.fail(TransportError::connect("connection refused"));
+        transport.respond(200, 2, "{}");
+
+        let client = BinanceSpot::builder()
+            .host("https://api.binance.test")
+            .transport(transport.clone())
+            .retry_policy(RetryPolicy::new(2).base_delay(Duration::from_millis(1)))
+            .build()
+            .unwrap();
+
+        let _response = client.ping().await.unwrap();
+        assert_eq!(transport.sent.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_memory_transport_error_is_returned() {
+        let transport = Arc::new(MemoryTransport::default());
+        transport.fail(TransportError::timeout("deadline elapsed"));
+
+        let client = BinanceSpot::builder()
+            .host("https://api.binance.test")
+            .transport(transport)
+            .build()
+            .unwrap();
+
+        match client.ping().await {
+            Err(BinanceError::Transport(err)) => assert!(err.is_timeout()),
+            other => panic!("expected transport error, got {:?}", other),
+        }
+    }
+
+    /// Records the URL of every attempt and whether a response came back.
+    #[derive(Default)]
+    struct RecordingInterceptor {
+        attempts: Mutex<Vec<(u32, String, bool)>>,
+    }
+
+    impl Interceptor for RecordingInterceptor {
+        fn after_response(&self, request: &RequestParts, outcome: Result<&ResponseMeta, &TransportError>) {
+            self.attempts
+                .lock()
+                .unwrap()
+                .push((request.attempt, request.url.to_owned(), outcome.is_ok()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_interceptor_sees_signed_url_of_every_attempt() {
+        use binancex::spot::trade::endpoints::API_V3_OPEN_ORDERS;
+
+        let transport = Arc::new(MemoryTransport::default());
+        transport.fail(TransportError::connect("connection refused"));
+        transport.respond(200, 3, "[]");
+        let interceptor = Arc::new(RecordingInterceptor::default());
+
+        let client = BinanceSpot::builder()
+            .host("https://api.binance.test")
+            .signed("api-key", "secret-key")
+            .transport(transport.clone())
+            .retry_policy(RetryPolicy::new(2).base_delay(Duration::from_millis(1)))
+            .interceptor(interceptor.clone())
+            .build()
+            .unwrap();
+
+        let _response = client.get_all_open_orders().await.unwrap();
+
+        let attempts = interceptor.attempts.lock().unwrap();
+        let sent = transport.sent.lock().unwrap();
+        assert_eq!(attempts.len(), 2);
+        for (i, (attempt, url, _)) in attempts.iter().enumerate() {
+            assert_eq!(*attempt, i as u32 + 1);
+            assert_eq!(*url, sent[i].url);
+            assert!(url.starts_with(&format!("https://api.binance.test{}?", API_V3_OPEN_ORDERS)));
+            assert!(url.contains("&signature="));
+        }
+        assert!(!attempts[0].2);
+        assert!(attempts[1].2);
+    }
+}